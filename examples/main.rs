@@ -28,6 +28,7 @@ fn get_random_packet(rng: &mut ThreadRng) -> GamePacket {
         game_info: GameInfo {
             seconds_elapsed: 0.,
             world_gravity_z: -650.,
+            ..Default::default()
         },
         num_cars: 64,
         game_cars: (0..64)
@@ -60,6 +61,7 @@ fn get_random_packet(rng: &mut ThreadRng) -> GamePacket {
                 ..Default::default()
             })
             .collect(),
+        ..Default::default()
     }
 }
 
@@ -144,15 +146,15 @@ fn main() {
 
         new_any_target(car_index, None).unwrap();
 
-        get_shot_with_target(0, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(1, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(2, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(3, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(3, Some(true), None, None, None, None, None).unwrap();
-        get_shot_with_target(4, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(5, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(6, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(7, None, None, None, None, None, None).unwrap();
-        get_shot_with_target(7, Some(true), None, None, None, None, None).unwrap();
+        get_shot_with_target(0, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(1, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(2, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(3, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(3, Some(true), None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(4, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(5, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(6, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(7, None, None, None, None, None, None, None, None).unwrap();
+        get_shot_with_target(7, Some(true), None, None, None, None, None, None, None).unwrap();
     }
 }
@@ -1,18 +1,20 @@
-use std::f32::consts::PI;
+use std::{cell::Cell, f32::consts::PI};
 
-use dubins_paths::{NoPathError, Result as DubinsResult};
-use glam::{Vec2, Vec3A};
+use glam::{Mat3A, Quat, Vec3A};
 
 use crate::{
     car::{Car, State},
     constants::*,
-    pytypes::{BasicShotInfo, ShotType},
+    ground::GroundTargetInfo,
+    hit::rotmat_from,
+    pytypes::{BasicShotInfo, ControllerState, ShotRejection, ShotType},
+    shot::AirBasedShot,
     BoostAmount, Mutators,
 };
 
 #[inline]
 fn angle_3d(a: Vec3A, b: Vec3A) -> f32 {
-    a.dot(b).acos()
+    a.dot(b).clamp(-1., 1.).acos()
 }
 
 #[derive(Debug)]
@@ -31,6 +33,25 @@ impl AerialTargetInfo {
     }
 }
 
+/// Driving up the wall and onto the ceiling, then dropping off of it into an aerial
+#[derive(Debug)]
+pub struct CeilingTargetInfo {
+    /// The path up the wall and across the ceiling to where the car should drop off
+    pub ground: GroundTargetInfo,
+    pub aerial: AerialTargetInfo,
+    pub leave_location: Vec3A,
+    /// The number of seconds from now until the car should drop off of the ceiling
+    pub leave_time: f32,
+}
+
+impl CeilingTargetInfo {
+    #[inline]
+    #[must_use]
+    pub const fn get_basic_shot_info(&self, time: f32) -> BasicShotInfo {
+        BasicShotInfo::found(time, ShotType::Ceiling, self.aerial.shot_vector, true, false)
+    }
+}
+
 /// Estimation of if a pre-established aerial shot is still possible
 #[must_use]
 pub fn partial_validate(
@@ -43,10 +64,13 @@ pub fn partial_validate(
     time_remaining: f32,
 ) -> bool {
     let delta_x = target - xf;
-    let Some(f) = delta_x.try_normalize() else {
+
+    // close enough to hit the ball without boosting any more
+    if delta_x.length() < AERIAL_AIM_DISTANCE {
         return true;
-    };
+    }
 
+    let f = delta_x.normalize();
     let required_acc = 2. * delta_x.length() / time_remaining.powi(2);
     let ratio = required_acc / boost_accel;
     if ratio.abs() > 1. {
@@ -61,65 +85,216 @@ pub fn partial_validate(
     (vf + f * (boost_accel * tau2)).length() <= MAX_SPEED
 }
 
+/// Flies the rest of a pre-established aerial shot tick by tick to see if it's still possible
+#[must_use]
+pub fn simulate_validate(car: &Car, shot: &AirBasedShot, gravity: Vec3A, mutators: Mutators, time_remaining: f32) -> bool {
+    let basic_aerial_info = BasicAerialInfo {
+        car_location: car.location,
+        car_velocity: car.velocity,
+        car_rotmat: car.rotmat,
+        car_angular_velocity: car.angular_velocity,
+        car_state: car.car_state,
+        car_air_time: car.air_time,
+        car_boost: f32::from(car.boost),
+        boost_amount: mutators.boost_amount,
+        boost_accel: mutators.boost_accel + AERIAL_THROTTLE_ACCEL,
+        gravity,
+        target: shot.final_target,
+        shot_vector: shot.shot_vector,
+        time_remaining,
+        simulate: true,
+        turn_direction: Vec3A::ZERO,
+        turn_time: Cell::new(None),
+    };
+
+    // the car never flies exactly like the simulation, so don't give up on the shot as soon as it drifts a little
+    basic_aerial_info.simulate(shot.jump_type, AERIAL_AIM_DISTANCE * 2.).is_ok()
+}
+
 #[derive(Debug)]
 struct BasicAerialInfo {
-    car_forward: Vec3A,
+    car_location: Vec3A,
+    car_velocity: Vec3A,
+    car_rotmat: Mat3A,
+    car_angular_velocity: Vec3A,
+    car_state: State,
+    car_air_time: f32,
     car_boost: f32,
     boost_amount: BoostAmount,
     boost_accel: f32,
+    gravity: Vec3A,
     target: Vec3A,
+    shot_vector: Vec3A,
     time_remaining: f32,
+    simulate: bool,
+    /// The direction that the car has to turn towards before it boosts, the same for every jump type
+    turn_direction: Vec3A,
+    /// How long the car takes to turn towards `turn_direction`, only simulated once a jump type needs it
+    turn_time: Cell<Option<Option<f32>>>,
 }
 
 impl BasicAerialInfo {
-    /// Estimation of if the aerial is valid
-    fn validate(&self, xf: Vec3A, vf: Vec3A, jump_type: AerialJumpType) -> Option<(AerialJumpType, f32)> {
-        let delta_x = self.target - xf;
-        let f = delta_x.normalize();
-        let phi = angle_3d(f, self.car_forward);
-
-        let turn_time = if phi < 0.1 {
-            TURN_TIME_POLY_EST[7] * phi as f64 + TURN_TIME_POLY_EST[8]
-        } else {
-            let x = Vec2::new(delta_x.z.atan2(delta_x.x), delta_x.y.atan2(delta_x.x)).length() as f64;
-
-            if x < 0.2 {
-                TURN_TIME_POLY_EST[9] * x.powi(2) + TURN_TIME_POLY_EST[10] * x + TURN_TIME_POLY_EST[11]
-            } else {
-                TURN_TIME_POLY_EST[0] * x.powi(6)
-                    + TURN_TIME_POLY_EST[1] * x.powi(5)
-                    + TURN_TIME_POLY_EST[2] * x.powi(4)
-                    + TURN_TIME_POLY_EST[3] * x.powi(3)
-                    + TURN_TIME_POLY_EST[4] * x.powi(2)
-                    + TURN_TIME_POLY_EST[5] * x
-                    + TURN_TIME_POLY_EST[6]
-            }
-        } as f32;
+    /// Jumping only changes how far up the car has to boost,
+    /// so the direction from where the car would fall to without any jumps is used for every jump type
+    fn turn_direction(&self, xf_base: Vec3A) -> Vec3A {
+        (self.target - (self.car_location + xf_base)).normalize_or_zero()
+    }
 
-        if turn_time > self.time_remaining {
-            return None;
+    /// Estimates how long the car takes to turn towards the direction that it has to boost in
+    ///
+    /// The turn is simulated the first time that it's needed, and then reused for the other jump types
+    fn turn_time(&self) -> Option<f32> {
+        if let Some(turn_time) = self.turn_time.get() {
+            return turn_time;
         }
 
-        let required_acc = 2. * delta_x.length() / (self.time_remaining - turn_time).powi(2);
+        let turn_time = turn_time(
+            self.car_rotmat,
+            self.car_angular_velocity,
+            self.turn_direction,
+            self.time_remaining,
+        );
+        self.turn_time.set(Some(turn_time));
+        turn_time
+    }
+
+    /// How long the car has to boost for to cover `distance` if it starts boosting after `turn_time`
+    ///
+    /// Turning for longer only leaves less time to boost,
+    /// so both the acceleration and the boost that's needed only go up as `turn_time` goes up
+    fn boost_time(&self, distance: f32, turn_time: f32) -> Result<f32, ShotRejection> {
+        let required_acc = 2. * distance / (self.time_remaining - turn_time).powi(2);
         let ratio = required_acc / self.boost_accel;
         if ratio.abs() >= 0.9 {
-            return None;
+            return Err(ShotRejection::AerialTooMuchAcceleration);
         }
 
         // when we stop boosting
         let tau2 = self.time_remaining - (self.time_remaining - turn_time) * (1. - ratio).sqrt();
+        let boost_time = tau2 - turn_time;
 
-        let boost_estimate = (tau2 - turn_time).floor() * BOOST_CONSUMPTION;
-        if self.boost_amount != BoostAmount::Unlimited && boost_estimate.ceil() >= self.car_boost {
-            return None;
+        if self.boost_amount != BoostAmount::Unlimited && (boost_time.floor() * BOOST_CONSUMPTION).ceil() >= self.car_boost {
+            return Err(ShotRejection::AerialBoostShortfall);
         }
 
+        Ok(boost_time)
+    }
+
+    /// Estimation of if the aerial is valid
+    fn validate(&self, xf: Vec3A, vf: Vec3A, jump_type: AerialJumpType) -> Result<(AerialJumpType, f32), ShotRejection> {
+        if self.simulate {
+            return self.simulate(jump_type, AERIAL_AIM_DISTANCE);
+        }
+
+        let delta_x = self.target - xf;
+        let f = delta_x.normalize();
+
+        // an instant turn is the best case, so rule out the aerials that can't work before simulating the turn
+        self.boost_time(delta_x.length(), 0.)?;
+
+        let turn_time = self.turn_time().ok_or(ShotRejection::AerialTurnTooSlow)?;
+        let boost_time = self.boost_time(delta_x.length(), turn_time)?;
+
         // velocity estimate
-        if (vf + f * (self.boost_accel * (tau2 - turn_time))).length() >= MAX_SPEED * 0.9 {
-            return None;
+        if (vf + f * (self.boost_accel * boost_time)).length() >= MAX_SPEED * 0.9 {
+            return Err(ShotRejection::AerialTooFast);
         }
 
-        Some((jump_type, boost_estimate))
+        Ok((jump_type, boost_time.floor() * BOOST_CONSUMPTION))
+    }
+
+    /// Flies the aerial tick by tick, the same way that `get_controls` does, to see if the car reaches the target
+    ///
+    /// The car has to end up within `max_miss` of the target
+    ///
+    /// Returns the amount of boost that the aerial used
+    fn simulate(&self, jump_type: AerialJumpType, max_miss: f32) -> Result<(AerialJumpType, f32), ShotRejection> {
+        let jumps = JumpSchedule::new(self.car_state, self.car_air_time, jump_type);
+        let has_boost = self.boost_amount != BoostAmount::NoBoost;
+
+        let mut location = self.car_location;
+        let mut velocity = self.car_velocity;
+        let mut rotmat = self.car_rotmat;
+        let mut angular_velocity = self.car_angular_velocity;
+        let mut second_jump = jumps.second_jump;
+        let mut boost_used = 0.;
+        let mut hit_max_speed = false;
+
+        if jumps.first_jump {
+            velocity += rotmat.z_axis * JUMP_SPEED;
+        }
+
+        let ticks = (self.time_remaining * TPS).round() as usize;
+        for tick in 0..ticks {
+            let t = tick as f32 * SIMULATION_DT;
+            let time_remaining = self.time_remaining - t;
+            let up = rotmat.z_axis;
+            let hold_time = (jumps.hold_time - t).max(0.);
+
+            // where the car will be if it stops boosting
+            let mut free_fall_location = location
+                + velocity * time_remaining
+                + self.gravity * 0.5 * time_remaining.powi(2)
+                + up * JUMP_ACC * hold_time * (time_remaining - hold_time / 2.);
+            if let Some(jump_time) = second_jump {
+                free_fall_location += up * JUMP_SPEED * (time_remaining - (jump_time - t).max(0.));
+            }
+
+            let delta_x = self.target - free_fall_location;
+            let is_aiming = delta_x.length() > AERIAL_AIM_DISTANCE;
+            let direction = if is_aiming { delta_x.normalize() } else { self.shot_vector };
+
+            let mut acceleration = self.gravity + rotmat.x_axis * AERIAL_THROTTLE_ACCEL;
+            if hold_time > 0. {
+                acceleration += up * JUMP_ACC;
+            }
+
+            let is_jumping_again = second_jump.map_or(false, |jump_time| jump_time <= t);
+            if is_jumping_again {
+                velocity += up * JUMP_SPEED;
+                second_jump = None;
+            }
+
+            let can_boost = has_boost && (self.boost_amount == BoostAmount::Unlimited || boost_used < self.car_boost);
+            if can_boost
+                && is_aiming
+                && angle_3d(rotmat.x_axis, direction) < AERIAL_BOOST_ANGLE
+                && self.boost_accel * SIMULATION_DT * time_remaining < delta_x.length()
+            {
+                acceleration += rotmat.x_axis * (self.boost_accel - AERIAL_THROTTLE_ACCEL);
+                boost_used += BOOST_CONSUMPTION_DT;
+            }
+
+            // the stick has to be neutral when jumping again, unless the first jump is still being held
+            let inputs = if is_jumping_again && hold_time <= 0. {
+                Vec3A::ZERO
+            } else {
+                reorient(rotmat, angular_velocity, facing(rotmat, direction))
+            };
+            (rotmat, angular_velocity) = rotate(rotmat, angular_velocity, inputs);
+
+            velocity += acceleration * SIMULATION_DT;
+            if velocity.length() > MAX_SPEED {
+                hit_max_speed = true;
+                velocity = velocity.clamp_length_max(MAX_SPEED);
+            }
+
+            location += velocity * SIMULATION_DT;
+        }
+
+        if location.distance(self.target) > max_miss {
+            let ran_out_of_boost = self.boost_amount != BoostAmount::Unlimited && boost_used >= self.car_boost;
+
+            return Err(if ran_out_of_boost {
+                ShotRejection::AerialBoostShortfall
+            } else if hit_max_speed {
+                ShotRejection::AerialTooFast
+            } else {
+                ShotRejection::AerialTooMuchAcceleration
+            });
+        }
+
+        Ok((jump_type, boost_used))
     }
 }
 
@@ -133,6 +308,10 @@ pub enum AerialJumpType {
     Double,
 }
 
+/// Checks if the car can aerial to `target` in time
+///
+/// If `simulate` is true, each jump type is flown tick by tick instead of estimated
+#[allow(clippy::too_many_arguments)]
 pub fn aerial_shot_is_viable(
     car: &Car,
     mutators: Mutators,
@@ -141,11 +320,12 @@ pub fn aerial_shot_is_viable(
     shot_vector: Vec3A,
     time_remaining: f32,
     check_target_angle: Option<Vec3A>,
-) -> DubinsResult<AerialTargetInfo> {
+    simulate: bool,
+) -> Result<AerialTargetInfo, ShotRejection> {
     let is_on_ground = car.car_state == State::Grounded || time_remaining > car.time_to_land;
 
     if is_on_ground && car.rotmat.z_axis.z >= 0. && time_remaining <= JUMP_MAX_DURATION {
-        return Err(NoPathError);
+        return Err(ShotRejection::AerialNotReady);
     }
 
     let land_time = if car.car_state != State::Grounded {
@@ -154,20 +334,22 @@ pub fn aerial_shot_is_viable(
         car.last_landing_time
     };
     if is_on_ground && land_time + ON_GROUND_WAIT_TIME > time_remaining {
-        return Err(NoPathError);
+        return Err(ShotRejection::AerialNotReady);
     }
 
     let quick_speed_required = car.location.distance(target) / time_remaining;
     if quick_speed_required > MAX_SPEED {
-        return Err(NoPathError);
+        return Err(ShotRejection::TooFar);
     }
 
     let max_car_speed = (car.velocity.length() + mutators.boost_accel * time_remaining).min(MAX_SPEED);
     if quick_speed_required > max_car_speed {
-        return Err(NoPathError);
+        return Err(ShotRejection::TooFar);
     }
 
     let mut found: Vec<(AerialJumpType, f32, bool)> = Vec::with_capacity(3);
+    // the reason the last jump type was rejected, reported if none of them work
+    let mut rejection = ShotRejection::AerialBadApproach;
 
     let boost_accel = mutators.boost_accel + AERIAL_THROTTLE_ACCEL;
 
@@ -184,15 +366,27 @@ pub fn aerial_shot_is_viable(
     if is_on_ground && ground_time_remaining > 0. && target_angle_check(car.landing_location) {
         const TOTAL_JUMP_ACC: f32 = JUMP_SPEED + JUMP_ACC * JUMP_MAX_DURATION;
 
-        let basic_aerial_info = BasicAerialInfo {
-            car_forward: car.landing_rotmat.x_axis,
+        // the car can't start turning until it's jumped off of the ground
+        let mut basic_aerial_info = BasicAerialInfo {
+            car_location: car.landing_location,
+            car_velocity: car.velocity,
+            car_rotmat: car.landing_rotmat,
+            car_angular_velocity: Vec3A::ZERO,
+            car_state: State::Grounded,
+            car_air_time: 0.,
             car_boost: f32::from(car.boost),
             boost_amount: mutators.boost_amount,
             boost_accel,
+            gravity,
             target,
+            shot_vector,
             time_remaining: ground_time_remaining,
+            simulate,
+            turn_direction: Vec3A::ZERO,
+            turn_time: Cell::new(None),
             // car,
         };
+        basic_aerial_info.turn_direction = basic_aerial_info.turn_direction(xf_base);
 
         if time_remaining > DOUBLE_JUMP_DURATION {
             const TOTAL_JUMP_ACC_2: f32 = JUMP_SPEED + TOTAL_JUMP_ACC;
@@ -203,8 +397,9 @@ pub fn aerial_shot_is_viable(
             let vf = vf_base + car.rotmat.z_axis * TOTAL_JUMP_ACC_2;
             let xf = car.landing_location + xf_base + car.rotmat.z_axis * (time_remaining * PARITAL_JUMP_LOC + JUMP_LOC_P2);
 
-            if let Some((jump_type, boost)) = basic_aerial_info.validate(xf, vf, AerialJumpType::Double) {
-                found.push((jump_type, boost, true));
+            match basic_aerial_info.validate(xf, vf, AerialJumpType::Double) {
+                Ok((jump_type, boost)) => found.push((jump_type, boost, true)),
+                Err(reason) => rejection = reason,
             }
         }
 
@@ -215,22 +410,34 @@ pub fn aerial_shot_is_viable(
             let vf = vf_base + car.rotmat.z_axis * TOTAL_JUMP_ACC;
             let xf = car.landing_location + xf_base + car.rotmat.z_axis * (time_remaining * PARITAL_JUMP_LOC + JUMP_LOC_P2);
 
-            if let Some((jump_type, boost)) = basic_aerial_info.validate(xf, vf, AerialJumpType::Normal) {
-                found.push((jump_type, boost, true));
+            match basic_aerial_info.validate(xf, vf, AerialJumpType::Normal) {
+                Ok((jump_type, boost)) => found.push((jump_type, boost, true)),
+                Err(reason) => rejection = reason,
             }
         }
     }
 
     if target_angle_check(car.location) {
-        let basic_aerial_info = BasicAerialInfo {
-            car_forward: car.rotmat.x_axis,
+        let mut basic_aerial_info = BasicAerialInfo {
+            car_location: car.location,
+            car_velocity: car.velocity,
+            car_rotmat: car.rotmat,
+            car_angular_velocity: car.angular_velocity,
+            car_state: car.car_state,
+            car_air_time: car.air_time,
             car_boost: f32::from(car.boost),
             boost_amount: mutators.boost_amount,
             boost_accel,
+            gravity,
             target,
+            shot_vector,
             time_remaining,
+            simulate,
+            turn_direction: Vec3A::ZERO,
+            turn_time: Cell::new(None),
             // car,
         };
+        basic_aerial_info.turn_direction = basic_aerial_info.turn_direction(xf_base);
 
         if car.car_state != State::DoubleJumped
             && (!is_on_ground
@@ -240,11 +447,13 @@ pub fn aerial_shot_is_viable(
                         + JUMP_SPEED
                         > 0.))
         {
-            let vf = vf_base + car.rotmat.z_axis * JUMP_SPEED;
-            let xf = car.location + xf_base + car.rotmat.z_axis * (JUMP_SPEED * time_remaining);
+            let jump = PendingJump::new(car, AerialJumpType::Secondary, time_remaining);
+            let vf = vf_base + jump.velocity;
+            let xf = car.location + xf_base + jump.displacement;
 
-            if let Some((jump_type, boost)) = basic_aerial_info.validate(xf, vf, AerialJumpType::Secondary) {
-                found.push((jump_type, boost, false));
+            match basic_aerial_info.validate(xf, vf, AerialJumpType::Secondary) {
+                Ok((jump_type, boost)) => found.push((jump_type, boost, false)),
+                Err(reason) => rejection = reason,
             }
         }
 
@@ -253,16 +462,15 @@ pub fn aerial_shot_is_viable(
             || (car.car_state != State::Grounded
                 && (car.velocity.z + gravity.z * car.time_to_land) + mutators.boost_accel * car.time_to_land > 0.)
         {
-            if let Some((jump_type, boost)) =
-                basic_aerial_info.validate(car.location + xf_base, vf_base, AerialJumpType::None)
-            {
-                found.push((jump_type, boost, false));
+            match basic_aerial_info.validate(car.location + xf_base, vf_base, AerialJumpType::None) {
+                Ok((jump_type, boost)) => found.push((jump_type, boost, false)),
+                Err(reason) => rejection = reason,
             }
         }
     }
 
     if found.is_empty() {
-        return Err(NoPathError);
+        return Err(rejection);
     }
 
     // println!("{found:?}");
@@ -273,7 +481,7 @@ pub fn aerial_shot_is_viable(
                 .partial_cmp(boost_estimate_2)
                 .unwrap_or_else(|| panic!("Invalid boost estimate: either {boost_estimate} ({jump_type:?}) or {boost_estimate_2} ({jump_type_2:?})"))
         })
-        .ok_or(NoPathError)?;
+        .ok_or(rejection)?;
 
     Ok(AerialTargetInfo {
         shot_vector,
@@ -282,3 +490,463 @@ pub fn aerial_shot_is_viable(
         wait_for_land: min_boost_estimate.2,
     })
 }
+
+/// How quickly the car turns to face the direction that it needs to boost in
+const AERIAL_TURN_FREQUENCY: f32 = 6.;
+/// When the car is this close to where it needs to be, it faces along the shot instead of boosting
+const AERIAL_AIM_DISTANCE: f32 = 50.;
+/// How closely the car has to face the direction that it needs to accelerate in before it boosts
+const AERIAL_BOOST_ANGLE: f32 = 0.3;
+/// How closely the car has to face the direction that it needs to accelerate in before it's done turning
+const AERIAL_TURNED_ANGLE: f32 = 0.1;
+/// The longest turn that's simulated, no matter how long the aerial is, since any turn that's possible takes less time
+const MAX_TURN_TIME: f32 = 3.;
+
+/// The orientation that faces `direction`
+///
+/// The roof keeps pointing the same way, unless the car has to turn it to face the right direction
+fn facing(rotmat: Mat3A, direction: Vec3A) -> Mat3A {
+    let up = if rotmat.z_axis.cross(direction).length_squared() > 0.01 {
+        rotmat.z_axis
+    } else {
+        -rotmat.x_axis
+    };
+
+    rotmat_from(direction, up)
+}
+
+/// The roll, pitch, and yaw inputs that turn a car with the orientation `rotmat` towards `target`, in that order
+///
+/// A critically damped PD controller around each of the car's local axes
+#[must_use]
+pub fn reorient(rotmat: Mat3A, angular_velocity: Vec3A, target: Mat3A) -> Vec3A {
+    let mut error = Quat::from_mat3a(&(rotmat.transpose() * target));
+    if error.w < 0. {
+        error = -error;
+    }
+
+    let (axis, angle) = error.to_axis_angle();
+    let local_angular_velocity = rotmat.transpose() * angular_velocity;
+
+    let angular_acceleration =
+        Vec3A::from(axis) * angle * AERIAL_TURN_FREQUENCY.powi(2) - local_angular_velocity * (2. * AERIAL_TURN_FREQUENCY);
+
+    // make up for the damping that slows the car's rotation down
+    let damping = Vec3A::new(ROLL_DAMPING, PITCH_DAMPING, YAW_DAMPING) * local_angular_velocity;
+
+    ((angular_acceleration - damping) / Vec3A::new(ROLL_TORQUE, PITCH_TORQUE, YAW_TORQUE)).clamp(Vec3A::NEG_ONE, Vec3A::ONE)
+}
+
+/// Rotates the car by one tick in the air with the roll, pitch, and yaw `inputs`
+///
+/// Returns the new orientation and angular velocity of the car
+fn rotate(rotmat: Mat3A, angular_velocity: Vec3A, inputs: Vec3A) -> (Mat3A, Vec3A) {
+    let local_angular_velocity = rotmat.transpose() * angular_velocity;
+
+    // there's only damping on pitch and yaw when there's no input on them
+    let damping = Vec3A::new(
+        ROLL_DAMPING,
+        PITCH_DAMPING * (1. - inputs.y.abs()),
+        YAW_DAMPING * (1. - inputs.z.abs()),
+    );
+    let angular_acceleration = Vec3A::new(ROLL_TORQUE, PITCH_TORQUE, YAW_TORQUE) * inputs + damping * local_angular_velocity;
+
+    let angular_velocity =
+        (rotmat * (local_angular_velocity + angular_acceleration * SIMULATION_DT)).clamp_length_max(MAX_ANGULAR_SPEED);
+
+    let rotation = angular_velocity * SIMULATION_DT;
+    let rotmat = match rotation.try_normalize() {
+        Some(axis) => Mat3A::from_axis_angle(axis.into(), rotation.length()) * rotmat,
+        None => rotmat,
+    };
+
+    (rotmat, angular_velocity)
+}
+
+/// How long it takes for the car to turn until it's facing `direction`
+///
+/// Simulates the car's rotation in the air, tick by tick, while it's being turned by `reorient`
+///
+/// Returns `None` if it takes longer than `max_time`, or longer than `MAX_TURN_TIME`
+#[must_use]
+pub fn turn_time(mut rotmat: Mat3A, mut angular_velocity: Vec3A, direction: Vec3A, max_time: f32) -> Option<f32> {
+    let max_ticks = (max_time.min(MAX_TURN_TIME) * TPS) as usize;
+
+    for tick in 0..=max_ticks {
+        if angle_3d(rotmat.x_axis, direction) < AERIAL_TURNED_ANGLE {
+            return Some(tick as f32 * SIMULATION_DT);
+        }
+
+        let inputs = reorient(rotmat, angular_velocity, facing(rotmat, direction));
+        (rotmat, angular_velocity) = rotate(rotmat, angular_velocity, inputs);
+    }
+
+    None
+}
+
+/// The part of an aerial's jumps that hasn't happened yet
+#[derive(Clone, Copy, Debug, Default)]
+pub struct PendingJump {
+    /// If jump should be held down this tick
+    pub press: bool,
+    /// How much further the rest of the jumps will move the car by the end of the aerial
+    pub displacement: Vec3A,
+    /// How much faster the rest of the jumps will make the car go
+    pub velocity: Vec3A,
+}
+
+impl PendingJump {
+    #[must_use]
+    pub fn new(car: &Car, jump_type: AerialJumpType, time_remaining: f32) -> Self {
+        let up = car.rotmat.z_axis;
+
+        match (jump_type, car.car_state) {
+            (AerialJumpType::Normal | AerialJumpType::Double, State::Grounded) => {
+                let mut jump = Self {
+                    press: car.wait_to_jump_time <= 0.,
+                    displacement: up
+                        * (time_remaining * (JUMP_SPEED + JUMP_ACC * JUMP_MAX_DURATION)
+                            - 0.5 * JUMP_MAX_DURATION * JUMP_MAX_DURATION * JUMP_ACC),
+                    velocity: up * (JUMP_SPEED + JUMP_ACC * JUMP_MAX_DURATION),
+                };
+
+                if jump_type == AerialJumpType::Double {
+                    jump.displacement += up * JUMP_SPEED * (time_remaining - DOUBLE_JUMP_DURATION);
+                    jump.velocity += up * JUMP_SPEED;
+                }
+
+                jump
+            }
+            (AerialJumpType::Normal | AerialJumpType::Double, State::Jumped) => {
+                // keep holding jump for the extra acceleration
+                let hold_time = (JUMP_MAX_DURATION - car.air_time).max(0.);
+                let mut jump = Self {
+                    press: hold_time > 0.,
+                    displacement: up * JUMP_ACC * hold_time * (time_remaining - hold_time / 2.),
+                    velocity: up * JUMP_ACC * hold_time,
+                };
+
+                // then let go of jump for a tick before pressing it again
+                if jump_type == AerialJumpType::Double {
+                    let wait_time = jump_again_wait(car.car_state, car.air_time);
+                    jump.press |= wait_time <= 0.;
+                    jump.displacement += up * JUMP_SPEED * (time_remaining - wait_time);
+                    jump.velocity += up * JUMP_SPEED;
+                }
+
+                jump
+            }
+            (AerialJumpType::Secondary, State::Jumped | State::Floating) => {
+                let wait_time = jump_again_wait(car.car_state, car.air_time);
+
+                Self {
+                    press: wait_time <= 0.,
+                    displacement: up * JUMP_SPEED * (time_remaining - wait_time),
+                    velocity: up * JUMP_SPEED,
+                }
+            }
+            _ => Self::default(),
+        }
+    }
+}
+
+/// How much longer jump has to be let go of before the car can jump again in the air
+///
+/// After jumping, jump is held for as long as it does anything and then let go of for a tick.
+/// A car that fell off of something instead lets go of jump for its first tick in the air.
+fn jump_again_wait(car_state: State, air_time: f32) -> f32 {
+    let release_time = if car_state == State::Floating {
+        SIMULATION_DT
+    } else {
+        DOUBLE_JUMP_DURATION
+    };
+
+    (release_time - air_time).max(0.)
+}
+
+/// When the jumps of an aerial happen, counted from the start of the aerial
+#[derive(Clone, Copy, Debug, Default)]
+struct JumpSchedule {
+    /// If the car jumps off of the ground at the start
+    first_jump: bool,
+    /// How much longer the first jump is held down for
+    hold_time: f32,
+    /// When the car jumps again in the air, if it does
+    second_jump: Option<f32>,
+}
+
+impl JumpSchedule {
+    fn new(car_state: State, air_time: f32, jump_type: AerialJumpType) -> Self {
+        let is_double = jump_type == AerialJumpType::Double;
+
+        match (jump_type, car_state) {
+            (AerialJumpType::Normal | AerialJumpType::Double, State::Grounded) => Self {
+                first_jump: true,
+                hold_time: JUMP_MAX_DURATION,
+                second_jump: is_double.then_some(DOUBLE_JUMP_DURATION),
+            },
+            (AerialJumpType::Normal | AerialJumpType::Double, State::Jumped) => Self {
+                first_jump: false,
+                hold_time: (JUMP_MAX_DURATION - air_time).max(0.),
+                second_jump: is_double.then_some(jump_again_wait(car_state, air_time)),
+            },
+            (AerialJumpType::Secondary, State::Jumped | State::Floating) => Self {
+                second_jump: Some(jump_again_wait(car_state, air_time)),
+                ..Default::default()
+            },
+            _ => Self::default(),
+        }
+    }
+}
+
+/// The controls that fly the car to the target of an aerial shot
+///
+/// The car jumps if the aerial calls for it,
+/// then points towards and boosts in the direction that it needs to accelerate in to reach the target on time
+#[must_use]
+pub fn get_controls(
+    car: &Car,
+    shot: &AirBasedShot,
+    gravity: Vec3A,
+    mutators: Mutators,
+    time_remaining: f32,
+) -> ControllerState {
+    let time_remaining = time_remaining.max(SIMULATION_DT);
+    let pending_jump = PendingJump::new(car, shot.jump_type, time_remaining);
+    let jump = pending_jump.press;
+
+    // where the car will be if it stops boosting
+    let free_fall_location =
+        car.location + car.velocity * time_remaining + gravity * 0.5 * time_remaining.powi(2) + pending_jump.displacement;
+    let delta_x = shot.final_target - free_fall_location;
+
+    let is_aiming = delta_x.length() > AERIAL_AIM_DISTANCE;
+    let direction = if is_aiming { delta_x.normalize() } else { shot.shot_vector };
+
+    let mut controls = ControllerState {
+        throttle: 1.,
+        jump,
+        ..Default::default()
+    };
+
+    if car.car_state == State::Grounded {
+        return controls;
+    }
+
+    // the stick has to be neutral when jumping again in the air, otherwise the car would dodge
+    let is_holding_first_jump = car.car_state == State::Jumped && car.air_time < JUMP_MAX_DURATION;
+    if !jump || is_holding_first_jump {
+        let inputs = reorient(car.rotmat, car.angular_velocity, facing(car.rotmat, direction));
+        controls.roll = inputs.x;
+        controls.pitch = inputs.y;
+        controls.yaw = inputs.z;
+    }
+
+    let boost_accel = if mutators.boost_amount == BoostAmount::NoBoost {
+        0.
+    } else {
+        mutators.boost_accel
+    };
+
+    // only boost if a tick of boost won't overshoot the target
+    let has_boost = car.boost > 0 || mutators.boost_amount == BoostAmount::Unlimited;
+    controls.boost = has_boost
+        && is_aiming
+        && angle_3d(car.rotmat.x_axis, direction) < AERIAL_BOOST_ANGLE
+        && (boost_accel + AERIAL_THROTTLE_ACCEL) * SIMULATION_DT * time_remaining < delta_x.length();
+
+    controls
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{cell::Cell, f32::consts::PI};
+
+    use glam::{Mat3A, Vec3A};
+
+    use crate::{
+        air::{
+            aerial_shot_is_viable, simulate_validate, turn_time, AerialJumpType, BasicAerialInfo, JumpSchedule, PendingJump,
+            MAX_TURN_TIME,
+        },
+        boost::standard_boost_pads,
+        car::{grounded_test_car, Arena, Car, State},
+        constants::{
+            AERIAL_THROTTLE_ACCEL, BOOST_ACCEL, DOUBLE_JUMP_DURATION, JUMP_MAX_DURATION, MAX_ANGULAR_SPEED, PITCH_TORQUE,
+            SIMULATION_DT,
+        },
+        pytypes::ShotRejection,
+        shot::AirBasedShot,
+        BoostAmount, Mutators,
+    };
+
+    /// A grounded car in the middle of the field with a full tank of boost, facing along the x axis
+    fn grounded_car() -> Car {
+        let mut car = grounded_test_car(Vec3A::new(0., 0., 17.), 0., 100);
+        car.init(-650., 720, Mutators::new(), &standard_boost_pads(), None, Arena::Soccar);
+        car
+    }
+
+    fn airborne_car(car_state: State, air_time: f32) -> Car {
+        let mut car = Car::new();
+        car.car_state = car_state;
+        car.air_time = air_time;
+        car
+    }
+
+    #[test]
+    pub fn secondary_jump_lets_go_first() {
+        // jump is still held from the first jump, so it has to be let go of before jumping again
+        for air_time in [
+            0.,
+            JUMP_MAX_DURATION / 2.,
+            JUMP_MAX_DURATION,
+            JUMP_MAX_DURATION + SIMULATION_DT,
+        ] {
+            let car = airborne_car(State::Jumped, air_time);
+            assert!(!PendingJump::new(&car, AerialJumpType::Secondary, 1.).press);
+
+            let schedule = JumpSchedule::new(State::Jumped, air_time, AerialJumpType::Secondary);
+            let second_jump = schedule.second_jump.unwrap();
+            assert!((second_jump - (DOUBLE_JUMP_DURATION - air_time)).abs() < 0.0001);
+            assert!(second_jump > 0.);
+        }
+
+        let car = airborne_car(State::Jumped, DOUBLE_JUMP_DURATION);
+        assert!(PendingJump::new(&car, AerialJumpType::Secondary, 1.).press);
+
+        // the first tick after falling off of something lets go of jump
+        let car = airborne_car(State::Floating, 0.);
+        assert!(!PendingJump::new(&car, AerialJumpType::Secondary, 1.).press);
+        let schedule = JumpSchedule::new(State::Floating, 0., AerialJumpType::Secondary);
+        assert_eq!(schedule.second_jump, Some(SIMULATION_DT));
+
+        let car = airborne_car(State::Floating, SIMULATION_DT);
+        assert!(PendingJump::new(&car, AerialJumpType::Secondary, 1.).press);
+        let schedule = JumpSchedule::new(State::Floating, SIMULATION_DT, AerialJumpType::Secondary);
+        assert_eq!(schedule.second_jump, Some(0.));
+    }
+
+    #[test]
+    pub fn double_jump_matches_secondary() {
+        for air_time in [0., JUMP_MAX_DURATION, DOUBLE_JUMP_DURATION] {
+            let double = JumpSchedule::new(State::Jumped, air_time, AerialJumpType::Double);
+            let secondary = JumpSchedule::new(State::Jumped, air_time, AerialJumpType::Secondary);
+            assert_eq!(double.second_jump, secondary.second_jump);
+        }
+    }
+
+    #[test]
+    pub fn backwards_turn_time() {
+        let direction = Vec3A::NEG_X;
+
+        // the quickest way to face backwards is to pitch over as hard as possible up to the max angular speed,
+        // then slow down just as hard at the end
+        let speed_up_time = MAX_ANGULAR_SPEED / PITCH_TORQUE.abs();
+        let speed_up_angle = MAX_ANGULAR_SPEED * speed_up_time / 2.;
+        let fastest_time = speed_up_time * 2. + (PI - speed_up_angle * 2.) / MAX_ANGULAR_SPEED;
+
+        let time = turn_time(Mat3A::IDENTITY, Vec3A::ZERO, direction, 3.).unwrap();
+        assert!(time > fastest_time * 0.9);
+        assert!(time < fastest_time * 1.3);
+
+        // already spinning the right way makes the turn quicker
+        let spinning_time = turn_time(Mat3A::IDENTITY, Vec3A::new(0., 0., 3.), direction, 3.).unwrap();
+        assert!(spinning_time < time);
+
+        // there's no turning left to do when the car is already facing the right way
+        assert_eq!(turn_time(Mat3A::IDENTITY, Vec3A::ZERO, Vec3A::X, 3.), Some(0.));
+
+        // turns that take longer than the time that there is are impossible, and the simulation stops no matter how long that is
+        assert_eq!(turn_time(Mat3A::IDENTITY, Vec3A::ZERO, direction, time / 2.), None);
+        assert!(turn_time(Mat3A::IDENTITY, Vec3A::ZERO, direction, f32::INFINITY).unwrap() <= MAX_TURN_TIME);
+    }
+
+    #[test]
+    pub fn turn_time_is_simulated_once() {
+        let car = grounded_car();
+        let target = Vec3A::new(0., 0., 1000.);
+
+        let basic_aerial_info = BasicAerialInfo {
+            car_location: car.location,
+            car_velocity: Vec3A::ZERO,
+            car_rotmat: car.rotmat,
+            car_angular_velocity: Vec3A::ZERO,
+            car_state: State::Grounded,
+            car_air_time: 0.,
+            car_boost: 100.,
+            boost_amount: BoostAmount::Default,
+            boost_accel: BOOST_ACCEL + AERIAL_THROTTLE_ACCEL,
+            gravity: Vec3A::new(0., 0., -650.),
+            target,
+            shot_vector: Vec3A::Z,
+            time_remaining: 1.5,
+            simulate: false,
+            turn_direction: Vec3A::Z,
+            turn_time: Cell::new(None),
+        };
+
+        // a target that's too far away even without turning doesn't need the turn to be simulated
+        let too_far = basic_aerial_info.validate(Vec3A::new(0., 0., -10_000.), Vec3A::ZERO, AerialJumpType::None);
+        assert_eq!(too_far.unwrap_err(), ShotRejection::AerialTooMuchAcceleration);
+        assert_eq!(basic_aerial_info.turn_time.get(), None);
+
+        // the first jump type that needs the turn simulates it, and the rest reuse it
+        let _ = basic_aerial_info.validate(car.location, Vec3A::ZERO, AerialJumpType::Normal);
+        let turn = basic_aerial_info.turn_time.get().unwrap();
+        assert_eq!(turn, turn_time(car.rotmat, Vec3A::ZERO, Vec3A::Z, 1.5));
+        assert!(turn.is_some());
+    }
+
+    #[test]
+    pub fn simulated_aerial() {
+        let car = grounded_car();
+        let gravity = Vec3A::new(0., 0., -650.);
+        let target = Vec3A::new(1000., 0., 800.);
+
+        let (time_remaining, target_info) = (10..30)
+            .map(|i| i as f32 / 10.)
+            .find_map(|time_remaining| {
+                aerial_shot_is_viable(&car, Mutators::new(), gravity, target, Vec3A::X, time_remaining, None, true)
+                    .ok()
+                    .map(|target_info| (time_remaining, target_info))
+            })
+            .unwrap();
+        assert_ne!(target_info.jump_type, AerialJumpType::None);
+
+        let shot = AirBasedShot {
+            time: time_remaining,
+            final_target: target_info.final_target,
+            jump_type: target_info.jump_type,
+            ball_location: target,
+            shot_vector: Vec3A::X,
+        };
+
+        // flying the same aerial again gets to the same place
+        assert!(simulate_validate(&car, &shot, gravity, Mutators::new(), time_remaining));
+
+        // but not if the target moved too far off to the side
+        let moved_shot = AirBasedShot {
+            final_target: shot.final_target + Vec3A::new(0., 2000., 0.),
+            ..shot
+        };
+        assert!(!simulate_validate(
+            &car,
+            &moved_shot,
+            gravity,
+            Mutators::new(),
+            time_remaining
+        ));
+
+        // or if the car ran out of boost
+        let mut empty_car = car.clone();
+        empty_car.boost = 0;
+        assert!(!simulate_validate(
+            &empty_car,
+            &shot,
+            gravity,
+            Mutators::new(),
+            time_remaining
+        ));
+    }
+}
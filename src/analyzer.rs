@@ -1,15 +1,19 @@
 use std::f32::consts::PI;
 
-use dubins_paths::{mod2pi, DubinsPath, NoPathError, PathType, PosRot, Result as DubinsResult};
-use glam::Vec3A;
+use dubins_paths::{mod2pi, DubinsPath, PathType, PosRot};
+use glam::{Mat3A, Vec3A};
 use rl_ball_sym::simulation::ball::Ball;
 
 use crate::{
-    air::{aerial_shot_is_viable, AerialTargetInfo},
-    car::{Car, State},
-    ground::{angle_2d, get_turn_exit_tanget, shortest_path_in_validate, GroundTargetInfo},
-    pytypes::ShotType,
+    air::{aerial_shot_is_viable, AerialTargetInfo, CeilingTargetInfo},
+    car::{turn_radius, Car, Obstacle, State},
+    constants::{DODGE_TO_HIT_TIME, MAX_SPEED, MIN_DODGE_DELAY, POWERSLIDE_TURN_SCALE, QUICK_TURN_SPEED},
+    dodge::Dodge,
+    ground::{angle_2d, get_turn_exit_tanget, shortest_path_in_validate, GroundTargetInfo, Turning},
+    pytypes::{ShotRejection, ShotType},
+    shot::Destination,
     utils::flatten,
+    wall::{Wall, TRANSITION_RADIUS},
     Mutators,
 };
 
@@ -19,15 +23,20 @@ enum Shot {
     Jump,
     DoubleJump,
     Aerial,
+    Ceiling,
+    Dodge,
 }
 
-#[derive(Clone, Copy, Debug)]
+#[derive(Clone, Debug)]
 pub struct Analyzer<'a> {
     pub max_speed: Option<f32>,
     pub max_turn_radius: Option<f32>,
     pub gravity: Vec3A,
-    pub may: [bool; 4],
+    pub may: [bool; 6],
     pub forwards_only: bool,
+    pub simulate_aerials: bool,
+    /// The other cars that paths have to stay clear of
+    pub obstacles: Vec<Obstacle>,
     pub car_front_length: f32,
     pub car: &'a Car,
 }
@@ -40,6 +49,8 @@ impl<'a> Analyzer<'a> {
             Shot::Jump => self.may[1],
             Shot::DoubleJump => self.may[2],
             Shot::Aerial => self.may[3],
+            Shot::Ceiling => self.may[4],
+            Shot::Dodge => self.may[5],
         }
     }
 
@@ -53,23 +64,63 @@ impl<'a> Analyzer<'a> {
         self.max_turn_radius.unwrap_or_else(|| self.car.ctrms[slice_num])
     }
 
+    /// The turn radius of a path, which is tighter if the car slows down or powerslides through the turns
+    #[inline]
+    fn get_path_turn_radius(&self, slice_num: usize, turning: Turning) -> f32 {
+        let rho = self.get_max_turn_radius(slice_num);
+
+        match turning {
+            Turning::Steer => rho,
+            // the car can't slow down in time to turn any tighter than it can at the speed it starts out with
+            Turning::QuickSteer => {
+                turn_radius(flatten(self.car.landing_velocity).length().clamp(QUICK_TURN_SPEED, MAX_SPEED)).min(rho)
+            }
+            Turning::Powerslide => rho / POWERSLIDE_TURN_SCALE,
+        }
+    }
+
+    #[inline]
+    fn max_ground_shot_height(&self) -> f32 {
+        self.car.hitbox.height / 2. + 17.
+    }
+
     /// get the type of shot that will be required to hit the ball
     /// also check if that type of shot has been enabled
-    pub fn get_shot_type(&self, target: Vec3A, time_remaining: f32) -> DubinsResult<ShotType> {
+    pub fn get_shot_type(&self, target: Vec3A, time_remaining: f32) -> Result<ShotType, ShotRejection> {
         if time_remaining < self.car.time_to_land {
             if self.may_shoot(Shot::Aerial) {
                 return Ok(ShotType::Aerial);
             }
-        } else if target.z < self.car.hitbox.height / 2. + 17. {
+        } else if target.z < self.max_ground_shot_height() {
             if self.may_shoot(Shot::Ground) {
                 return Ok(ShotType::Ground);
             }
+
+            if self.may_shoot(Shot::Dodge) {
+                return Ok(ShotType::Dodge);
+            }
         } else if target.z < self.car.max_jump_height {
+            // dodging into the ball hits it much harder than just jumping into it
+            if self.may_shoot(Shot::Dodge) {
+                return Ok(ShotType::Dodge);
+            }
+
             if self.may_shoot(Shot::Jump) {
                 return Ok(ShotType::Jump);
             }
         } else if target.z < self.car.max_double_jump_height && self.may_shoot(Shot::DoubleJump) {
             return Ok(ShotType::DoubleJump);
+        } else if let Some(wall) = self.car.field.find_wall(target, self.car.max_jump_height) {
+            // wall shots are ground shots or jump shots that happen on the wall
+            let shot = if wall.distance_to(target) < self.max_ground_shot_height() {
+                Shot::Ground
+            } else {
+                Shot::Jump
+            };
+
+            if self.may_shoot(shot) {
+                return Ok(ShotType::Wall);
+            }
         }
 
         if self.car.car_state != State::Grounded
@@ -78,7 +129,17 @@ impl<'a> Analyzer<'a> {
             return Ok(ShotType::Aerial);
         }
 
-        Err(NoPathError)
+        if self.may_shoot(Shot::Ceiling) {
+            return Ok(ShotType::Ceiling);
+        }
+
+        Err(ShotRejection::ShotTypeUnavailable)
+    }
+
+    /// Dodge shots replace jump shots, so check if a jump shot could be tried if the dodge doesn't work out
+    #[inline]
+    pub fn may_jump_instead_of_dodge(&self, target: Vec3A) -> bool {
+        self.may_shoot(Shot::Jump) && (self.max_ground_shot_height()..self.car.max_jump_height).contains(&target.z)
     }
 
     fn get_jump_info(
@@ -89,7 +150,7 @@ impl<'a> Analyzer<'a> {
         max_speed: f32,
         time_remaining: f32,
         shot_type: ShotType,
-    ) -> DubinsResult<(Option<f32>, f32)> {
+    ) -> Result<(Option<f32>, f32), ShotRejection> {
         Ok(match shot_type {
             ShotType::Ground => {
                 let distance = 320.;
@@ -115,7 +176,7 @@ impl<'a> Analyzer<'a> {
             ShotType::DoubleJump => {
                 // if we need to do a double jump but we don't even have time for a normal jump
                 if time_remaining < self.car.max_jump_time {
-                    return Err(NoPathError);
+                    return Err(ShotRejection::NotEnoughJumpTime);
                 }
 
                 let time = self
@@ -124,10 +185,40 @@ impl<'a> Analyzer<'a> {
 
                 (Some(time), time * max_speed + 128.)
             }
-            ShotType::Aerial => unreachable!(),
+            ShotType::Dodge => {
+                // jump up to the height of the ball, then dodge into it
+                let time = self
+                    .car
+                    .jump_time_to_height(self.gravity.z, target.z - self.car.hitbox.height / 2.)
+                    .max(MIN_DODGE_DELAY)
+                    + DODGE_TO_HIT_TIME;
+
+                (Some(time), time * max_speed + 128.)
+            }
+            ShotType::Aerial | ShotType::Wall | ShotType::Ceiling => unreachable!(),
         })
     }
 
+    /// Plan the dodge for dodge shots, assuming the car drives the path at a constant speed
+    fn get_dodge(
+        &self,
+        ball: &Ball,
+        shot_vector: Vec3A,
+        distances: &[f32; 4],
+        time_remaining: f32,
+        jump_time: Option<f32>,
+        shot_type: ShotType,
+    ) -> Option<Dodge> {
+        if shot_type != ShotType::Dodge {
+            return None;
+        }
+
+        let jump_time = jump_time?;
+        let car_speed = (distances.iter().sum::<f32>() / time_remaining).min(MAX_SPEED);
+
+        Some(Dodge::new(jump_time, ball, shot_vector, car_speed, self.car))
+    }
+
     #[inline]
     fn should_travel_forwards(&self, time_remaining: f32, shot_vector: Vec3A) -> bool {
         // it's easier for me to think about what I want the criteria to be for going backwards, so I did that then just took the opposite of it for is_forwards
@@ -145,17 +236,24 @@ impl<'a> Analyzer<'a> {
         mut time_remaining: f32,
         slice_num: usize,
         shot_type: ShotType,
-    ) -> DubinsResult<GroundTargetInfo> {
+        turning: Turning,
+    ) -> Result<GroundTargetInfo, ShotRejection> {
+        if shot_type == ShotType::Wall {
+            return self.wall_target(ball, None, time_remaining, slice_num, turning);
+        }
+
         let max_speed = self.get_max_speed(slice_num);
 
         time_remaining -= self.car.time_to_land;
-        assert!(time_remaining > 0.);
+        if time_remaining <= 0. {
+            return Err(ShotRejection::NoTime);
+        }
         let car_location = flatten(self.car.landing_location);
         let max_distance = time_remaining * max_speed + self.car_front_length + ball.radius();
 
         // check if a simplified path is longer than the longest distance we can possibly travel
         if car_location.distance(flatten(ball.location)) > max_distance {
-            return Err(NoPathError);
+            return Err(ShotRejection::TooFar);
         }
 
         let car_to_ball = (ball.location - self.car.location).normalize_or_zero();
@@ -176,12 +274,13 @@ impl<'a> Analyzer<'a> {
         if let Some(jump_time) = jump_time {
             // if we have enough time for just the jump
             if jump_time > time_remaining {
-                return Err(NoPathError);
+                return Err(ShotRejection::NotEnoughJumpTime);
             }
         }
 
-        let rho = self.get_max_turn_radius(slice_num);
-        let is_forwards = self.should_travel_forwards(time_remaining, car_to_ball);
+        let rho = self.get_path_turn_radius(slice_num, turning);
+        // dodge shots need to be driving forwards to dodge forwards into the ball
+        let is_forwards = shot_type == ShotType::Dodge || self.should_travel_forwards(time_remaining, car_to_ball);
         let local_ball = self.car.localize_2d_location(ball.location);
         let target_is_forwards = local_ball.x >= 0.;
         let should_turn_left = local_ball.y < 0.;
@@ -205,7 +304,7 @@ impl<'a> Analyzer<'a> {
 
         // check if the exit point is in the field
         if !self.car.field.is_point_in(turn_target) {
-            return Err(NoPathError);
+            return Err(ShotRejection::ExitOutOfField);
         }
 
         // compute the distance of each path, validating that it is within our current maximum travel distance (returning an error if neither are)
@@ -213,8 +312,12 @@ impl<'a> Analyzer<'a> {
         let turn_final_distance = turn_target.distance(ball.location) - ball.radius() - self.car_front_length;
         let offset_distance = end_distance - self.car_front_length - ball.radius();
 
-        if turn_final_distance < offset_distance || turn_final_distance + turn_target.distance(car_location) > max_distance {
-            return Err(NoPathError);
+        if turn_final_distance < offset_distance {
+            return Err(ShotRejection::TooClose);
+        }
+
+        if turn_final_distance + turn_target.distance(car_location) > max_distance {
+            return Err(ShotRejection::TooFar);
         }
 
         let shot_vector = (flatten(ball.location) - turn_target).normalize_or_zero();
@@ -238,7 +341,7 @@ impl<'a> Analyzer<'a> {
         let turn_arc_distance = turn_angle * rho;
 
         if turn_final_distance + turn_arc_distance > max_distance {
-            return Err(NoPathError);
+            return Err(ShotRejection::TooFar);
         }
 
         let enter_yaw = if is_forwards {
@@ -256,6 +359,7 @@ impl<'a> Analyzer<'a> {
         };
 
         let distances = [turn_arc_distance, 0., 0., turn_final_distance];
+        let dodge = self.get_dodge(ball, shot_vector, &distances, time_remaining, jump_time, shot_type);
 
         Ok(GroundTargetInfo {
             distances,
@@ -266,30 +370,99 @@ impl<'a> Analyzer<'a> {
             shot_vector,
             turn_targets: Some((turn_target, turn_target_2)),
             wait_for_land: self.car.car_state != State::Grounded,
+            wall: None,
+            dodge,
+            turning,
         })
     }
 
     pub fn target(
+        &self,
+        ball: &Ball,
+        shot_vector: Vec3A,
+        time_remaining: f32,
+        slice_num: usize,
+        shot_type: ShotType,
+        turning: Turning,
+    ) -> Result<GroundTargetInfo, ShotRejection> {
+        if shot_type == ShotType::Wall {
+            return self.wall_target(ball, Some(shot_vector), time_remaining, slice_num, turning);
+        }
+
+        self.target_on(ball, shot_vector, time_remaining, slice_num, shot_type, None, turning)
+    }
+
+    /// Find a path to a ball on the wall by unfolding the wall into the floor plane
+    ///
+    /// If there's no shot vector, the car will hit the ball in the direction it approaches it from
+    fn wall_target(
+        &self,
+        ball: &Ball,
+        shot_vector: Option<Vec3A>,
+        time_remaining: f32,
+        slice_num: usize,
+        turning: Turning,
+    ) -> Result<GroundTargetInfo, ShotRejection> {
+        let wall = *self
+            .car
+            .field
+            .find_wall(ball.location, self.car.max_jump_height)
+            .ok_or(ShotRejection::ShotTypeUnavailable)?;
+
+        let mut unfolded_ball = *ball;
+        unfolded_ball.location = wall.unfold(ball.location);
+
+        let shot_vector = match shot_vector {
+            Some(shot_vector) => wall.unfold_vector(shot_vector),
+            None => flatten(unfolded_ball.location - self.car.landing_location).normalize_or_zero(),
+        };
+
+        let shot_type = if unfolded_ball.location.z < self.max_ground_shot_height() {
+            ShotType::Ground
+        } else {
+            ShotType::Jump
+        };
+
+        let mut target_info = self.target_on(
+            &unfolded_ball,
+            shot_vector,
+            time_remaining,
+            slice_num,
+            shot_type,
+            Some(&wall),
+            turning,
+        )?;
+        target_info.shot_type = ShotType::Wall;
+
+        Ok(target_info)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn target_on(
         &self,
         ball: &Ball,
         shot_vector: Vec3A,
         mut time_remaining: f32,
         slice_num: usize,
         shot_type: ShotType,
-    ) -> DubinsResult<GroundTargetInfo> {
+        wall: Option<&Wall>,
+        turning: Turning,
+    ) -> Result<GroundTargetInfo, ShotRejection> {
         let offset_target = ball.location - (shot_vector * ball.radius());
         let car_front_length = (self.car.hitbox_offset.x + self.car.hitbox.length) / 2.;
 
         let max_speed = self.get_max_speed(slice_num);
 
         time_remaining -= self.car.time_to_land;
-        assert!(time_remaining > 0.);
+        if time_remaining <= 0. {
+            return Err(ShotRejection::NoTime);
+        }
         let car_location = self.car.landing_location;
         let max_distance = time_remaining * max_speed + car_front_length;
 
         // check if a simplified path is longer than the longest distance we can possibly travel
         if flatten(car_location).distance(flatten(offset_target)) > max_distance {
-            return Err(NoPathError);
+            return Err(ShotRejection::TooFar);
         }
         let (jump_time, end_distance) = self.get_jump_info(
             ball.location,
@@ -303,17 +476,19 @@ impl<'a> Analyzer<'a> {
         if let Some(jump_time) = jump_time {
             // if we have enough time for just the jump
             if jump_time > time_remaining {
-                return Err(NoPathError);
+                return Err(ShotRejection::NotEnoughJumpTime);
             }
         }
 
         let exit_turn_target = flatten(offset_target) - (flatten(shot_vector).normalize_or_zero() * end_distance);
 
         // check if the exit point is in the field, and make sure a simplified version of the path isn't longer than the longest distance we can travel
-        if !self.car.field.is_point_in(flatten(exit_turn_target))
-            || flatten(car_location).distance(exit_turn_target) + end_distance > max_distance
-        {
-            return Err(NoPathError);
+        if !self.car.field.is_point_on(flatten(exit_turn_target), wall) {
+            return Err(ShotRejection::ExitOutOfField);
+        }
+
+        if flatten(car_location).distance(exit_turn_target) + end_distance > max_distance {
+            return Err(ShotRejection::TooFar);
         }
 
         // calculate and return the dubin's path
@@ -321,7 +496,9 @@ impl<'a> Analyzer<'a> {
         let target_angle = shot_vector.y.atan2(shot_vector.x);
         let mut starting_yaw = self.car.landing_yaw;
 
-        let is_forwards = self.should_travel_forwards(time_remaining, shot_vector);
+        // driving backwards up a wall isn't worth the risk, and dodge shots need to dodge forwards into the ball
+        let is_forwards =
+            wall.is_some() || shot_type == ShotType::Dodge || self.should_travel_forwards(time_remaining, shot_vector);
 
         if !is_forwards {
             starting_yaw += PI;
@@ -330,7 +507,15 @@ impl<'a> Analyzer<'a> {
         let q0 = PosRot::new(flatten(car_location), starting_yaw);
         let q1 = PosRot::new(flatten(exit_turn_target), target_angle);
 
-        let path = shortest_path_in_validate(q0, q1, self.get_max_turn_radius(slice_num), &self.car.field, max_distance)?;
+        let path = shortest_path_in_validate(
+            q0,
+            q1,
+            self.get_path_turn_radius(slice_num, turning),
+            &self.car.field,
+            wall,
+            max_distance,
+        )
+        .map_err(|_| ShotRejection::NoPathInField)?;
 
         let offset_distance = end_distance - car_front_length;
         let distances = [
@@ -339,6 +524,7 @@ impl<'a> Analyzer<'a> {
             path.segment_length(2),
             offset_distance,
         ];
+        let dodge = self.get_dodge(ball, shot_vector, &distances, time_remaining, jump_time, shot_type);
 
         Ok(GroundTargetInfo {
             distances,
@@ -349,6 +535,211 @@ impl<'a> Analyzer<'a> {
             shot_vector,
             turn_targets: None,
             wait_for_land: self.car.car_state != State::Grounded,
+            wall: wall.copied(),
+            dodge,
+            turning,
+        })
+    }
+
+    pub fn destination(
+        &self,
+        destination: &Destination,
+        mut time_remaining: f32,
+        slice_num: usize,
+        turning: Turning,
+    ) -> Result<GroundTargetInfo, ShotRejection> {
+        time_remaining -= self.car.time_to_land;
+
+        if time_remaining <= 0. {
+            return Err(ShotRejection::NoTime);
+        }
+
+        let car_location = flatten(self.car.landing_location);
+        let location = flatten(destination.location);
+
+        if !self.car.field.is_point_in(location) {
+            return Err(ShotRejection::ExitOutOfField);
+        }
+
+        let max_distance = time_remaining * self.get_max_speed(slice_num);
+
+        // check if a simplified path is longer than the longest distance we can possibly travel
+        if car_location.distance(location) > max_distance {
+            return Err(ShotRejection::TooFar);
+        }
+
+        let arrival_yaw = destination.arrival_yaw.unwrap_or_else(|| {
+            let direction = location - car_location;
+            direction.y.atan2(direction.x)
+        });
+        let arrival_direction = Vec3A::new(arrival_yaw.cos(), arrival_yaw.sin(), 0.);
+
+        let is_forwards = self.should_travel_forwards(time_remaining, arrival_direction);
+
+        // when driving backwards, the car will face the opposite way that it's traveling
+        let (starting_yaw, ending_yaw) = if is_forwards {
+            (self.car.landing_yaw, arrival_yaw)
+        } else {
+            (self.car.landing_yaw + PI, arrival_yaw + PI)
+        };
+
+        let q0 = PosRot::new(car_location, starting_yaw);
+        let q1 = PosRot::new(location, ending_yaw);
+        let shot_vector = Vec3A::new(ending_yaw.cos(), ending_yaw.sin(), 0.);
+
+        let path = shortest_path_in_validate(
+            q0,
+            q1,
+            self.get_path_turn_radius(slice_num, turning),
+            &self.car.field,
+            None,
+            max_distance,
+        )
+        .map_err(|_| ShotRejection::NoPathInField)?;
+
+        let distances = [path.segment_length(0), path.segment_length(1), path.segment_length(2), 0.];
+
+        Ok(GroundTargetInfo {
+            distances,
+            shot_type: ShotType::Ground,
+            path,
+            jump_time: None,
+            is_forwards,
+            shot_vector,
+            turn_targets: None,
+            wait_for_land: self.car.car_state != State::Grounded,
+            wall: None,
+            dodge: None,
+            turning,
+        })
+    }
+
+    /// Drive up the wall closest to the target and onto the ceiling, then drop off of the ceiling into an aerial
+    ///
+    /// The car drops off far enough from the wall to clear the curve between the wall and the ceiling,
+    /// and falls for as long as it would take to fall from the ceiling to the target
+    pub fn ceiling_shot(
+        &self,
+        mutators: Mutators,
+        target: Vec3A,
+        shot_vector: Vec3A,
+        time_remaining: f32,
+        slice_num: usize,
+        check_target_angle: Option<Vec3A>,
+    ) -> Result<CeilingTargetInfo, ShotRejection> {
+        if !self.may_shoot(Shot::Ceiling) || self.car.car_state != State::Grounded {
+            return Err(ShotRejection::ShotTypeUnavailable);
+        }
+
+        let margin = self.car.field.margin();
+        let ceiling_distance = TRANSITION_RADIUS + margin;
+        let car_location = flatten(self.car.landing_location);
+
+        // drop off of the ceiling above the closest wall, as close to the target as possible
+        let (wall, leave_location, along) = self
+            .car
+            .field
+            .walls()
+            .iter()
+            .map(|wall| {
+                let along = wall.clamp_along(target, margin);
+                let leave_location = wall.fold(
+                    wall.unfolded_point(along, wall.ceiling() + ceiling_distance) + Vec3A::Z * self.car.hitbox.height / 2.,
+                );
+                (wall, leave_location, along)
+            })
+            .min_by(|(_, a, _), (_, b, _)| {
+                flatten(*a - target)
+                    .length_squared()
+                    .total_cmp(&flatten(*b - target).length_squared())
+            })
+            .ok_or(ShotRejection::ShotTypeUnavailable)?;
+
+        let fall_distance = leave_location.z - target.z;
+        if fall_distance <= 0. || self.gravity.z >= 0. {
+            return Err(ShotRejection::ShotTypeUnavailable);
+        }
+
+        let fall_time = (2. * fall_distance / -self.gravity.z).sqrt();
+        let leave_time = time_remaining - fall_time;
+
+        if leave_time <= 0. {
+            return Err(ShotRejection::NoTime);
+        }
+
+        let max_distance = leave_time * self.get_max_speed(slice_num);
+
+        // the path ends near the top of the wall, facing straight up it
+        let exit_turn_target = wall.unfolded_point(along, wall.top() - margin * 2.);
+        let end_distance = wall.ceiling() + ceiling_distance - (wall.top() - margin * 2.);
+
+        if car_location.distance(exit_turn_target) + end_distance > max_distance {
+            return Err(ShotRejection::TooFar);
+        }
+
+        let up_wall = wall.outward();
+        let q0 = PosRot::new(car_location, self.car.landing_yaw);
+        let q1 = PosRot::new(exit_turn_target, up_wall.y.atan2(up_wall.x));
+
+        let path = shortest_path_in_validate(
+            q0,
+            q1,
+            self.get_max_turn_radius(slice_num),
+            &self.car.field,
+            Some(wall),
+            max_distance,
+        )
+        .map_err(|_| ShotRejection::NoPathInField)?;
+
+        let ground = GroundTargetInfo {
+            distances: [
+                path.segment_length(0),
+                path.segment_length(1),
+                path.segment_length(2),
+                end_distance,
+            ],
+            path,
+            shot_type: ShotType::Ceiling,
+            jump_time: None,
+            is_forwards: true,
+            shot_vector: up_wall,
+            turn_targets: None,
+            wait_for_land: false,
+            wall: Some(*wall),
+            dodge: None,
+            turning: Turning::Steer,
+        };
+
+        ground.can_reach(self.car, leave_time, mutators)?;
+
+        // a car that's just dropped off of the ceiling, upside down with its flip still available
+        let mut ceiling_car = Car::new();
+        let forward = -up_wall;
+        ceiling_car.location = leave_location;
+        ceiling_car.landing_location = leave_location;
+        ceiling_car.velocity = forward * (ground.distances.iter().sum::<f32>() / leave_time).min(MAX_SPEED);
+        ceiling_car.rotmat = Mat3A::from_cols(forward, Vec3A::Z.cross(forward), Vec3A::NEG_Z);
+        ceiling_car.hitbox = self.car.hitbox;
+        ceiling_car.boost = self.car.boost;
+        ceiling_car.car_state = State::Floating;
+        ceiling_car.time_to_land = f32::INFINITY;
+
+        let aerial = aerial_shot_is_viable(
+            &ceiling_car,
+            mutators,
+            self.gravity,
+            target,
+            shot_vector,
+            fall_time,
+            check_target_angle,
+            self.simulate_aerials,
+        )?;
+
+        Ok(CeilingTargetInfo {
+            ground,
+            aerial,
+            leave_location,
+            leave_time,
         })
     }
 
@@ -360,7 +751,7 @@ impl<'a> Analyzer<'a> {
         shot_vector: Vec3A,
         time_remaining: f32,
         check_target_angle: Option<Vec3A>,
-    ) -> DubinsResult<AerialTargetInfo> {
+    ) -> Result<AerialTargetInfo, ShotRejection> {
         aerial_shot_is_viable(
             self.car,
             mutators,
@@ -369,6 +760,114 @@ impl<'a> Analyzer<'a> {
             shot_vector,
             time_remaining,
             check_target_angle,
+            self.simulate_aerials,
         )
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+
+    use glam::Vec3A;
+
+    use crate::{
+        analyzer::Analyzer,
+        boost::standard_boost_pads,
+        car::{grounded_test_car, Arena, Car},
+        field::FieldGrid,
+        ground::Turning,
+        pytypes::{ShotRejection, ShotType},
+        Mutators,
+    };
+
+    /// A grounded car with a full tank of boost, at `location` facing along `yaw`
+    fn grounded_car(location: Vec3A, yaw: f32) -> Car {
+        let mut car = grounded_test_car(location, yaw, 100);
+
+        let (game, ball) = rl_ball_sym::compressed::load_standard();
+        let field = Some(Arc::new(FieldGrid::from_game(&game, ball)));
+
+        car.init(-650., 720, Mutators::new(), &standard_boost_pads(), field, Arena::Soccar);
+        car
+    }
+
+    fn new_analyzer(car: &Car, may: [bool; 6]) -> Analyzer<'_> {
+        Analyzer {
+            max_speed: None,
+            max_turn_radius: None,
+            gravity: Vec3A::new(0., 0., -650.),
+            may,
+            forwards_only: false,
+            simulate_aerials: false,
+            obstacles: Vec::new(),
+            car_front_length: (car.hitbox_offset.x + car.hitbox.length) / 2.,
+            car,
+        }
+    }
+
+    #[test]
+    pub fn ceiling_shot() {
+        let car = grounded_car(Vec3A::new(2500., 0., 17.), 0.);
+        let analyzer = new_analyzer(&car, [false, false, false, false, true, false]);
+        let target = Vec3A::new(1500., 0., 1400.);
+        let shot_vector = Vec3A::NEG_Y;
+        let ceiling_shot = |target, time_remaining: f32| {
+            let slice_num = (time_remaining * 120.).round() as usize - 1;
+            analyzer.ceiling_shot(Mutators::new(), target, shot_vector, time_remaining, slice_num, None)
+        };
+
+        let (time_remaining, info) = (30..50)
+            .map(|i| i as f32 / 10.)
+            .find_map(|time_remaining| ceiling_shot(target, time_remaining).ok().map(|info| (time_remaining, info)))
+            .unwrap();
+
+        // the car goes up the closest wall, and drops off of the ceiling above it
+        assert_eq!(info.ground.wall.unwrap().outward(), Vec3A::X);
+        assert!(info.leave_location.x > 3500. && info.leave_location.x < 4096.);
+        assert!(info.leave_location.z > 2000.);
+
+        // and falls for as long as it would take to fall from the ceiling to the target
+        let fall_time = (2. * (info.leave_location.z - target.z) / 650.).sqrt();
+        assert!((info.leave_time + fall_time - time_remaining).abs() < 0.001);
+
+        // not enough time to fall from the ceiling, and a target that's above the ceiling
+        assert_eq!(ceiling_shot(target, 1.).unwrap_err(), ShotRejection::NoTime);
+        assert_eq!(
+            ceiling_shot(Vec3A::new(1500., 0., 2100.), time_remaining).unwrap_err(),
+            ShotRejection::ShotTypeUnavailable
+        );
+
+        let analyzer = new_analyzer(&car, [true, true, true, true, false, true]);
+        assert_eq!(
+            analyzer
+                .ceiling_shot(Mutators::new(), target, shot_vector, time_remaining, 479, None)
+                .unwrap_err(),
+            ShotRejection::ShotTypeUnavailable
+        );
+    }
+
+    #[test]
+    pub fn no_time_after_landing() {
+        let mut car = grounded_car(Vec3A::new(0., -1000., 17.), 0.);
+        // the car is still in the air, and won't land until after the ball gets there
+        car.time_to_land = 0.5;
+        let analyzer = new_analyzer(&car, [true; 6]);
+
+        let (_, mut ball) = rl_ball_sym::compressed::load_standard();
+        ball.location = Vec3A::new(0., -800., 92.75);
+
+        assert_eq!(
+            analyzer
+                .no_target(&ball, 0.3, 35, ShotType::Ground, Turning::Steer)
+                .unwrap_err(),
+            ShotRejection::NoTime
+        );
+        assert_eq!(
+            analyzer
+                .target_on(&ball, Vec3A::Y, 0.5, 59, ShotType::Jump, None, Turning::Steer)
+                .unwrap_err(),
+            ShotRejection::NoTime
+        );
+    }
+}
@@ -0,0 +1,383 @@
+use dubins_paths::{DubinsPath, PosRot};
+use glam::Vec3A;
+
+use crate::{
+    car::{Car, State},
+    constants::TPS,
+    ground::{shortest_path_in_validate, GroundTargetInfo, Turning},
+    pytypes::{BoostPadOptions, BoostPadRoute, GameBoost, GameFieldInfo, ShotType},
+    utils::{flatten, get_tuple_from_vec3},
+    Mutators,
+};
+
+/// The number of seconds it takes for a small pad to respawn
+pub const SMALL_PAD_RESPAWN_TIME: f32 = 4.;
+/// The number of seconds it takes for a large pad to respawn
+pub const FULL_PAD_RESPAWN_TIME: f32 = 10.;
+pub const SMALL_PAD_RADIUS: f32 = 144.;
+pub const FULL_PAD_RADIUS: f32 = 208.;
+pub const SMALL_PAD_AMOUNT: f32 = 12.;
+pub const FULL_PAD_AMOUNT: f32 = 100.;
+/// How much longer than driving straight to the point a detour through a pad can be
+/// for the pad to be considered between the car and the point
+const MAX_DETOUR_RATIO: f32 = 1.2;
+const PATH_SAMPLE_DISTANCE: f32 = 30.;
+const ROUTE_SEARCH_STEP: usize = 6;
+
+/// x, y, z, and is_full_boost for every pad on a standard soccar field, in the same order as RLBot
+const STANDARD_BOOST_PADS: [(f32, f32, f32, bool); 34] = [
+    (0., -4240., 70., false),
+    (-1792., -4184., 70., false),
+    (1792., -4184., 70., false),
+    (-3072., -4096., 73., true),
+    (3072., -4096., 73., true),
+    (-940., -3308., 70., false),
+    (940., -3308., 70., false),
+    (0., -2816., 70., false),
+    (-3584., -2484., 70., false),
+    (3584., -2484., 70., false),
+    (-1788., -2300., 70., false),
+    (1788., -2300., 70., false),
+    (-2048., -1036., 70., false),
+    (0., -1024., 70., false),
+    (2048., -1036., 70., false),
+    (-3584., 0., 73., true),
+    (-1024., 0., 70., false),
+    (1024., 0., 70., false),
+    (3584., 0., 73., true),
+    (-2048., 1036., 70., false),
+    (0., 1024., 70., false),
+    (2048., 1036., 70., false),
+    (-1788., 2300., 70., false),
+    (1788., 2300., 70., false),
+    (-3584., 2484., 70., false),
+    (3584., 2484., 70., false),
+    (0., 2816., 70., false),
+    (-940., 3310., 70., false),
+    (940., 3308., 70., false),
+    (-3072., 4096., 73., true),
+    (3072., 4096., 73., true),
+    (-1792., 4184., 70., false),
+    (1792., 4184., 70., false),
+    (0., 4240., 70., false),
+];
+
+#[derive(Clone, Copy, Debug)]
+pub struct BoostPad {
+    pub location: Vec3A,
+    pub is_full_boost: bool,
+    pub is_active: bool,
+    /// Seconds since the pad was picked up
+    pub timer: f32,
+}
+
+impl BoostPad {
+    #[inline]
+    #[must_use]
+    pub const fn new(location: Vec3A, is_full_boost: bool) -> Self {
+        Self {
+            location,
+            is_full_boost,
+            is_active: true,
+            timer: 0.,
+        }
+    }
+
+    #[inline]
+    pub fn update(&mut self, state: GameBoost) {
+        self.is_active = state.is_active;
+        self.timer = state.timer;
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn amount(&self) -> f32 {
+        if self.is_full_boost {
+            FULL_PAD_AMOUNT
+        } else {
+            SMALL_PAD_AMOUNT
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn radius(&self) -> f32 {
+        if self.is_full_boost {
+            FULL_PAD_RADIUS
+        } else {
+            SMALL_PAD_RADIUS
+        }
+    }
+
+    /// The number of seconds until the pad can be picked up again
+    #[inline]
+    #[must_use]
+    pub fn time_until_active(&self) -> f32 {
+        if self.is_active {
+            0.
+        } else if self.is_full_boost {
+            (FULL_PAD_RESPAWN_TIME - self.timer).max(0.)
+        } else {
+            (SMALL_PAD_RESPAWN_TIME - self.timer).max(0.)
+        }
+    }
+
+    /// Checks if the pad is on the given team's half of the field, including the center line
+    #[inline]
+    #[must_use]
+    pub fn is_on_side(&self, team: u8) -> bool {
+        if team == 0 {
+            self.location.y <= 0.
+        } else {
+            self.location.y >= 0.
+        }
+    }
+
+    /// Checks if driving through the pad is only a small detour from driving straight from `start` to `end`
+    #[inline]
+    #[must_use]
+    pub fn is_between(&self, start: Vec3A, end: Vec3A) -> bool {
+        let (start, end, location) = (flatten(start), flatten(end), flatten(self.location));
+
+        start.distance(location) + location.distance(end) <= start.distance(end) * MAX_DETOUR_RATIO
+    }
+}
+
+/// Checks if the car can drive over the pad by the given slice, and gets the path it should take
+fn route_at_slice(
+    car: &Car,
+    pad: &BoostPad,
+    arrival_direction: Vec3A,
+    slice_num: usize,
+    mutators: Mutators,
+) -> Option<(f32, DubinsPath)> {
+    let car_location = flatten(car.landing_location);
+    let pad_location = flatten(pad.location);
+
+    // the first slice is a tick after now
+    let time = (slice_num + 1) as f32 / TPS;
+    let max_distance = (time - car.time_to_land) * car.max_speed[slice_num];
+
+    // check if a simplified path is longer than the longest distance we can possibly travel
+    if car_location.distance(pad_location) > max_distance {
+        return None;
+    }
+
+    let q0 = PosRot::new(car_location, car.landing_yaw);
+    let q1 = PosRot::new(pad_location, arrival_direction.y.atan2(arrival_direction.x));
+
+    let path = shortest_path_in_validate(q0, q1, car.ctrms[slice_num], &car.field, None, max_distance).ok()?;
+
+    let target_info = GroundTargetInfo {
+        distances: [path.segment_length(0), path.segment_length(1), path.segment_length(2), 0.],
+        path,
+        shot_type: ShotType::Ground,
+        jump_time: None,
+        is_forwards: true,
+        shot_vector: arrival_direction,
+        turn_targets: None,
+        wait_for_land: car.car_state != State::Grounded,
+        wall: None,
+        dodge: None,
+        turning: Turning::Steer,
+    };
+
+    target_info.can_reach(car, time, mutators).ok()?;
+
+    Some((time.max(pad.time_until_active()), path))
+}
+
+/// Find the soonest time that the car can drive over the pad, along with the path it should take
+fn find_pad_route(car: &Car, pad: &BoostPad, arrival_direction: Vec3A, mutators: Mutators) -> Option<(f32, DubinsPath)> {
+    let try_slice = |slice_num| route_at_slice(car, pad, arrival_direction, slice_num, mutators);
+
+    let start_slice = (car.time_to_land * TPS).ceil() as usize;
+
+    // search coarsely, then go back through the skipped slices to find the exact one
+    let slice_num = (start_slice..car.max_speed.len())
+        .step_by(ROUTE_SEARCH_STEP)
+        .find(|&slice_num| try_slice(slice_num).is_some())?;
+
+    (slice_num.saturating_sub(ROUTE_SEARCH_STEP - 1).max(start_slice)..=slice_num).find_map(try_slice)
+}
+
+/// Get the routes to every pad that the car can reach before the end of the ball prediction, soonest first
+///
+/// The car must already be initialized
+#[must_use]
+pub fn get_boost_pad_routes(
+    car: &Car,
+    boost_pads: &[BoostPad],
+    options: BoostPadOptions,
+    mutators: Mutators,
+) -> Vec<BoostPadRoute> {
+    if car.car_state == State::Demolished {
+        return Vec::new();
+    }
+
+    let own_half = options.own_half.unwrap_or(false);
+    let between = options.between.map(Vec3A::from);
+
+    let mut routes: Vec<_> = boost_pads
+        .iter()
+        .enumerate()
+        .filter(|(_, pad)| !own_half || pad.is_on_side(car.team))
+        .filter(|(_, pad)| between.map_or(true, |point| pad.is_between(car.landing_location, point)))
+        .filter_map(|(pad_index, pad)| {
+            // when heading towards a point, arrive at the pad already facing it
+            let arrival_direction = flatten(match between {
+                Some(point) => point - pad.location,
+                None => pad.location - car.landing_location,
+            })
+            .normalize_or_zero();
+
+            let (time, path) = find_pad_route(car, pad, arrival_direction, mutators)?;
+
+            Some(BoostPadRoute {
+                pad_index,
+                location: get_tuple_from_vec3(pad.location),
+                is_full_boost: pad.is_full_boost,
+                time,
+                distance: path.length(),
+                path_samples: path
+                    .sample_many(PATH_SAMPLE_DISTANCE)
+                    .into_iter()
+                    .map(|posrot| (posrot.pos.x, posrot.pos.y))
+                    .collect(),
+            })
+        })
+        .collect();
+
+    routes.sort_unstable_by(|a, b| a.time.total_cmp(&b.time));
+    routes
+}
+
+#[must_use]
+pub fn standard_boost_pads() -> Vec<BoostPad> {
+    STANDARD_BOOST_PADS
+        .iter()
+        .map(|&(x, y, z, is_full_boost)| BoostPad::new(Vec3A::new(x, y, z), is_full_boost))
+        .collect()
+}
+
+impl From<GameFieldInfo> for Vec<BoostPad> {
+    #[inline]
+    fn from(field_info: GameFieldInfo) -> Self {
+        field_info
+            .boost_pads
+            .into_iter()
+            .take(field_info.num_boosts)
+            .map(|pad| BoostPad::new(pad.location.into(), pad.is_full_boost))
+            .collect()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::FRAC_PI_2, sync::Arc};
+
+    use glam::Vec3A;
+
+    use crate::{
+        boost::{find_pad_route, get_boost_pad_routes, route_at_slice, standard_boost_pads},
+        car::{grounded_test_car, Arena, Car},
+        constants::TPS,
+        field::FieldGrid,
+        pytypes::BoostPadOptions,
+        Mutators,
+    };
+
+    /// A car sitting in front of the blue goal, facing the orange goal
+    fn car_in_front_of_goal() -> Car {
+        let mut car = grounded_test_car(Vec3A::new(0., -3300., 17.), FRAC_PI_2, 0);
+
+        let (game, ball) = rl_ball_sym::compressed::load_standard();
+        let field = Some(Arc::new(FieldGrid::from_game(&game, ball)));
+
+        car.init(-650., 720, Mutators::new(), &standard_boost_pads(), field, Arena::Soccar);
+        car
+    }
+
+    #[test]
+    pub fn nearest_pad_route() {
+        let car = car_in_front_of_goal();
+        let boost_pads = standard_boost_pads();
+
+        let routes = get_boost_pad_routes(&car, &boost_pads, BoostPadOptions::default(), Mutators::new());
+
+        // the small pad about 500uu straight ahead of the car is the soonest
+        let nearest = &routes[0];
+        assert_eq!(nearest.pad_index, 7);
+        assert!(nearest.time < 1.);
+        assert!((nearest.distance - 484.).abs() < 10.);
+        assert!(routes.windows(2).all(|pair| pair[0].time <= pair[1].time));
+
+        // only the pads on blue's half
+        let own_half = BoostPadOptions {
+            own_half: Some(true),
+            between: None,
+        };
+        let routes = get_boost_pad_routes(&car, &boost_pads, own_half, Mutators::new());
+        assert!(routes.iter().all(|route| route.location.1 <= 0.));
+    }
+
+    #[test]
+    pub fn coarse_route_search_finds_the_exact_slice() {
+        let car = car_in_front_of_goal();
+        let mut num_routes = 0;
+
+        for pad in standard_boost_pads() {
+            let arrival_direction = (pad.location - car.landing_location).normalize_or_zero();
+            let route = find_pad_route(&car, &pad, arrival_direction, Mutators::new());
+
+            let start_slice = (car.time_to_land * TPS).ceil() as usize;
+            let exact = (start_slice..car.max_speed.len())
+                .find_map(|slice_num| route_at_slice(&car, &pad, arrival_direction, slice_num, Mutators::new()));
+
+            assert_eq!(route.map(|(time, _)| time), exact.map(|(time, _)| time));
+
+            // the route's time is the time of the slice that it was found at, the same as the ball slice at that index
+            if let Some((time, _)) = route {
+                let slice_num = (time * TPS).round() as usize - 1;
+                if time > pad.time_until_active() {
+                    assert!(route_at_slice(&car, &pad, arrival_direction, slice_num, Mutators::new()).is_some());
+                    assert!(route_at_slice(&car, &pad, arrival_direction, slice_num - 1, Mutators::new()).is_none());
+                }
+            }
+            num_routes += usize::from(route.is_some());
+        }
+
+        // most of the field can be reached in the 6 seconds
+        assert!(num_routes > 20);
+    }
+
+    #[test]
+    pub fn routes_on_the_way_to_a_point() {
+        let car = car_in_front_of_goal();
+        let boost_pads = standard_boost_pads();
+        let point = Vec3A::new(0., 3000., 0.);
+
+        let between = BoostPadOptions {
+            own_half: None,
+            between: Some((point.x, point.y, point.z)),
+        };
+        let routes = get_boost_pad_routes(&car, &boost_pads, between, Mutators::new());
+
+        // the pads up the middle of the field, but not the ones in the corners
+        assert!(!routes.is_empty());
+        assert!(routes
+            .iter()
+            .all(|route| boost_pads[route.pad_index].is_between(car.landing_location, point)));
+        assert!(routes.iter().all(|route| !boost_pads[route.pad_index].is_full_boost));
+
+        // the car arrives at each pad already heading towards the point
+        for route in &routes {
+            let (x, y) = route.path_samples[route.path_samples.len() - 2];
+            let (end_x, end_y) = route.path_samples[route.path_samples.len() - 1];
+            let heading = Vec3A::new(end_x - x, end_y - y, 0.).normalize();
+            let to_point = (point - Vec3A::new(end_x, end_y, 0.)).normalize();
+
+            assert!(heading.dot(to_point) > 0.9);
+        }
+    }
+}
@@ -1,10 +1,15 @@
+use std::sync::Arc;
+
 use dubins_paths::{DubinsPath, PosRot};
 use glam::{Mat3A, Quat, Vec3A};
 
 use crate::{
+    boost::BoostPad,
     constants::*,
-    pytypes::{GameCar, Hitbox},
+    field::FieldGrid,
+    pytypes::{GameCar, GameScoreInfo, Hitbox},
     utils::{flatten, minimum_non_negative, vertex_quadratic_solve_for_x},
+    wall::{Wall, SOCCAR_WALLS, THROWBACK_WALLS},
     BoostAmount, Mutators,
 };
 
@@ -47,12 +52,103 @@ pub fn turn_radius(v: f32) -> f32 {
     1. / curvature(v)
 }
 
-#[derive(Clone, Copy, Debug, Default)]
+/// The fastest that the car can go while still being able to turn with a radius of `radius`
+#[must_use]
+pub fn max_turn_speed(radius: f32) -> f32 {
+    let k = 1. / radius;
+
+    if k >= 0.003_98 {
+        ((0.0069 - k) / 5.84e-6).max(0.)
+    } else if k >= 0.002_35 {
+        (0.00561 - k) / 3.26e-6
+    } else if k >= 0.001_375 {
+        (0.0043 - k) / 1.95e-6
+    } else if k >= 0.0011 {
+        (0.003_025 - k) / 1.1e-6
+    } else {
+        ((0.0018 - k) / 4e-7).min(2295.)
+    }
+}
+
+/// The type of arena that was loaded
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Arena {
+    #[default]
+    Soccar,
+    Throwback,
+    Hoops,
+    Dropshot,
+}
+
+impl Arena {
+    /// The distance from the center of the field to each goal line, if the ball can be scored by crossing it
+    #[inline]
+    #[must_use]
+    pub const fn goal_line(self) -> Option<f32> {
+        match self {
+            Arena::Soccar => Some(5120.),
+            Arena::Throwback => Some(4900.),
+            Arena::Hoops | Arena::Dropshot => None,
+        }
+    }
+
+    /// Half of the width and the height of the goal frame, if the arena's goals have one
+    ///
+    /// Hoops scores through a ring instead of between posts and dropshot has no goals at all,
+    /// so neither has posts or a crossbar that the ball can hit
+    #[inline]
+    #[must_use]
+    pub const fn goal_frame(self) -> Option<(f32, f32)> {
+        match self {
+            Arena::Soccar => Some((893., 642.775)),
+            // throwback's goals are wider, but just as tall
+            Arena::Throwback => Some((940., 642.775)),
+            Arena::Hoops | Arena::Dropshot => None,
+        }
+    }
+
+    /// The left and right goal posts of the goal that `team` defends, from the point of view of a car shooting into it,
+    /// halfway up the goal
+    ///
+    /// Returns `None` if the arena doesn't have goals with posts
+    #[inline]
+    #[must_use]
+    pub fn goal_posts(self, team: u8) -> Option<(Vec3A, Vec3A)> {
+        let goal_line = self.goal_line()?;
+        let (half_width, height) = self.goal_frame()?;
+        // blue defends the goal on the negative y side of the field, and the x axis points to the left of a car facing +y
+        let side = if team == 0 { -1. } else { 1. };
+
+        Some((
+            Vec3A::new(side * half_width, side * goal_line, height / 2.),
+            Vec3A::new(-side * half_width, side * goal_line, height / 2.),
+        ))
+    }
+
+    /// If the arena has boost pads, whether or not their layout is known
+    #[inline]
+    #[must_use]
+    pub const fn has_boost_pads(self) -> bool {
+        !matches!(self, Arena::Dropshot)
+    }
+
+    /// The flat walls that paths can drive onto
+    #[inline]
+    #[must_use]
+    pub const fn walls(self) -> &'static [Wall] {
+        match self {
+            Arena::Soccar => &SOCCAR_WALLS,
+            Arena::Throwback => &THROWBACK_WALLS,
+            Arena::Hoops | Arena::Dropshot => &[],
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
 pub struct FieldRect {
-    goal_x: f32,
-    goal_y: f32,
-    field_y: f32,
-    field_x: f32,
+    grid: Option<Arc<FieldGrid>>,
+    walls: &'static [Wall],
+    margin: f32,
 }
 
 impl FieldRect {
@@ -62,27 +158,25 @@ impl FieldRect {
     #[must_use]
     pub const fn new() -> Self {
         Self {
-            goal_x: 0.,
-            goal_y: 0.,
-            field_y: 0.,
-            field_x: 0.,
+            grid: None,
+            walls: &[],
+            margin: 0.,
         }
     }
 
+    #[inline]
     #[must_use]
-    pub fn from(car_hitbox: &Hitbox) -> Self {
-        let half_car_len = car_hitbox.length / 2.;
-
+    pub fn from(car_hitbox: &Hitbox, grid: Option<Arc<FieldGrid>>, walls: &'static [Wall]) -> Self {
         Self {
-            goal_x: 893. - car_hitbox.width,
-            goal_y: 6000. - car_hitbox.length,
-            field_y: 5120. - half_car_len,
-            field_x: 4093. - half_car_len,
+            grid,
+            walls,
+            margin: car_hitbox.length / 2.,
         }
     }
 
+    /// Checks if the path stays in the field, or on the unfolded wall if one is given
     #[must_use]
-    pub fn is_path_in(&self, path: &DubinsPath) -> bool {
+    pub fn is_path_in(&self, path: &DubinsPath, wall: Option<&Wall>) -> bool {
         let length = path.length();
 
         let types = path.type_.to_segment_types();
@@ -110,7 +204,7 @@ impl FieldRect {
 
             let sample = (q.pos * path.rho) + path.qi.pos;
 
-            if !self.is_point_in(sample) {
+            if !self.is_point_on(sample, wall) {
                 return false;
             }
 
@@ -120,16 +214,85 @@ impl FieldRect {
         true
     }
 
+    #[inline]
     #[must_use]
     pub fn is_point_in(&self, p: Vec3A) -> bool {
-        let p = [p.x.abs(), p.y.abs()];
+        self.grid.as_ref().map_or(false, |grid| grid.clearance(p) > self.margin)
+    }
 
-        if p[0] > self.goal_x {
-            p[0] < self.field_x && p[1] < self.field_y
-        } else {
-            p[1] < self.goal_y
+    /// Checks if the point is in the field, or on the unfolded wall if one is given
+    #[inline]
+    #[must_use]
+    pub fn is_point_on(&self, p: Vec3A, wall: Option<&Wall>) -> bool {
+        self.is_point_in(p) || wall.map_or(false, |wall| wall.contains_unfolded(p, self.margin))
+    }
+
+    /// How far the center of the car has to stay from the edges of the field
+    #[inline]
+    #[must_use]
+    pub const fn margin(&self) -> f32 {
+        self.margin
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn walls(&self) -> &'static [Wall] {
+        self.walls
+    }
+
+    /// Find the wall that the ball is on, if the car could reach it from the wall
+    #[inline]
+    #[must_use]
+    pub fn find_wall(&self, ball_location: Vec3A, max_distance: f32) -> Option<&Wall> {
+        self.walls.iter().find(|wall| wall.is_touching(ball_location, max_distance))
+    }
+}
+
+/// Another car that paths have to stay clear of
+#[derive(Clone, Copy, Debug)]
+pub struct Obstacle {
+    location: Vec3A,
+    velocity: Vec3A,
+    is_grounded: bool,
+    /// How close the two cars' centers can get before they touch
+    radius: f32,
+}
+
+impl Obstacle {
+    /// The height of the car's center while it's resting on the ground
+    const RESTING_HEIGHT: f32 = 17.;
+
+    #[inline]
+    #[must_use]
+    pub fn from(car: &Car, other: &Car) -> Self {
+        Self {
+            location: other.location,
+            velocity: other.velocity,
+            is_grounded: other.car_state == State::Grounded,
+            radius: (car.hitbox.length + other.hitbox.length) / 2.,
         }
     }
+
+    /// Where the other car will be after `time`, if it keeps driving the way that it is,
+    /// or falls to the ground if it's in the air
+    #[must_use]
+    pub fn location_at(&self, time: f32, gravity: Vec3A) -> Vec3A {
+        let mut location = self.location + self.velocity * time;
+
+        if !self.is_grounded {
+            location += gravity * (time * time / 2.);
+            location.z = location.z.max(Self::RESTING_HEIGHT);
+        }
+
+        location
+    }
+
+    /// Checks if a car at `location` after `time` would run into the other car
+    #[inline]
+    #[must_use]
+    pub fn blocks(&self, location: Vec3A, time: f32, gravity: Vec3A) -> bool {
+        self.location_at(time, gravity).distance(location) < self.radius
+    }
 }
 
 #[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
@@ -160,6 +323,13 @@ pub struct Car {
     pub yaw: f32,
     pub roll: f32,
     pub boost: u8,
+    pub team: u8,
+    pub name: String,
+    /// Stays the same for as long as the car is in the match, even if the other cars are reordered
+    pub spawn_id: i32,
+    pub is_bot: bool,
+    pub is_super_sonic: bool,
+    pub score_info: GameScoreInfo,
     pub car_state: State,
     pub time_to_land: f32,
     pub landing_location: Vec3A,
@@ -172,6 +342,13 @@ pub struct Car {
     pub landing_quat: Quat,
     last_landing_game_time: f32,
     pub last_landing_time: f32,
+    last_grounded_game_time: f32,
+    /// How long the car has been off of the ground for, or 0 if it's on the ground
+    pub air_time: f32,
+    /// The fastest the car can be going at each slice, if it drives straight ahead after landing
+    ///
+    /// This is shared by every path that's checked, so it only counts the boost pads that are straight ahead of the car,
+    /// and not the ones that a path would turn through
     pub max_speed: Vec<f32>,
     /// turn radius at calculated max speed
     pub ctrms: Vec<f32>,
@@ -183,6 +360,13 @@ pub struct Car {
     pub init: bool,
 }
 
+impl Default for Car {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
 impl Car {
     #[inline]
     #[must_use]
@@ -204,6 +388,12 @@ impl Car {
             yaw: 0.,
             roll: 0.,
             boost: 0,
+            team: 0,
+            name: String::new(),
+            spawn_id: 0,
+            is_bot: false,
+            is_super_sonic: false,
+            score_info: GameScoreInfo::new(),
             car_state: State::Grounded,
             time_to_land: 0.,
             landing_location: Vec3A::ZERO,
@@ -216,6 +406,8 @@ impl Car {
             landing_quat: Quat::IDENTITY,
             last_landing_game_time: 0.,
             last_landing_time: 0.,
+            last_grounded_game_time: 0.,
+            air_time: 0.,
             max_speed: Vec::new(),
             ctrms: Vec::new(),
             max_jump_time: 0.,
@@ -240,6 +432,12 @@ impl Car {
         self.hitbox_offset = py_car.hitbox_offset.into();
 
         self.boost = py_car.boost;
+        self.team = py_car.team;
+        self.name = py_car.name;
+        self.spawn_id = py_car.spawn_id;
+        self.is_bot = py_car.is_bot;
+        self.is_super_sonic = py_car.is_super_sonic;
+        self.score_info = py_car.score_info;
 
         if self.car_state != State::Grounded && py_car.has_wheel_contact {
             self.last_landing_game_time = game_time;
@@ -247,6 +445,12 @@ impl Car {
 
         self.last_landing_time = self.last_landing_game_time - game_time;
 
+        if py_car.has_wheel_contact {
+            self.last_grounded_game_time = game_time;
+        }
+
+        self.air_time = game_time - self.last_grounded_game_time;
+
         if py_car.is_demolished {
             self.car_state = State::Demolished;
         } else if py_car.has_wheel_contact {
@@ -262,13 +466,21 @@ impl Car {
         self.init = false;
     }
 
-    pub fn init(&mut self, gravity: f32, max_ball_slice: usize, mutators: Mutators) {
+    pub fn init(
+        &mut self,
+        gravity: f32,
+        max_ball_slice: usize,
+        mutators: Mutators,
+        boost_pads: &[BoostPad],
+        field: Option<Arc<FieldGrid>>,
+        arena: Arena,
+    ) {
         if !self.init {
             Self::calculate_orientation_matrix(&mut self.quat, &mut self.rotmat, self.pitch, self.yaw, self.roll);
-            self.calculate_field();
+            self.calculate_field(field, arena);
             self.calculate_landing_info(gravity);
             self.calculate_local_values();
-            self.calculate_max_values(max_ball_slice, mutators);
+            self.calculate_max_values(max_ball_slice, mutators, boost_pads);
             self.calculate_max_jump_height(gravity);
             self.calculate_max_double_jump_height(gravity);
 
@@ -443,135 +655,126 @@ impl Car {
         *quat = Quat::from_mat3a(&rotmat.transpose());
     }
 
-    fn calculate_max_values(&mut self, max_ball_slice: usize, mutators: Mutators) {
+    /// Get the distance along the car's heading, the time until it's available, and the amount of boost
+    /// for every pad that the car would drive over if it went straight ahead after landing
+    fn get_pads_ahead(&self, boost_pads: &[BoostPad]) -> Vec<(f32, f32, f32)> {
+        let forward = flatten(self.landing_rotmat.x_axis).normalize_or_zero();
+
+        let mut pads: Vec<_> = boost_pads
+            .iter()
+            .filter_map(|pad| {
+                let offset = flatten(pad.location - self.landing_location);
+                let distance_along = offset.dot(forward);
+                let distance_to_side = (offset - forward * distance_along).length();
+
+                (distance_along > 0. && distance_to_side < pad.radius())
+                    .then(|| (distance_along, pad.time_until_active() - self.time_to_land, pad.amount()))
+            })
+            .collect();
+
+        // furthest first so the closest pad can be popped off of the end
+        pads.sort_unstable_by(|a, b| b.0.total_cmp(&a.0));
+        pads
+    }
+
+    fn calculate_max_values(&mut self, max_ball_slice: usize, mutators: Mutators, boost_pads: &[BoostPad]) {
+        self.max_speed = self.simulate_max_speed(max_ball_slice, mutators, self.get_pads_ahead(boost_pads));
+
+        // the pads are only on the straight line ahead of the car, so curved paths can't count on them when turning
+        self.ctrms = self
+            .simulate_max_speed(max_ball_slice, mutators, Vec::new())
+            .into_iter()
+            .map(turn_radius)
+            .collect();
+    }
+
+    /// The fastest that the car can be going at each slice if it drives straight ahead,
+    /// picking up the boost from `pads_ahead` as it drives over them
+    fn simulate_max_speed(
+        &self,
+        max_ball_slice: usize,
+        mutators: Mutators,
+        mut pads_ahead: Vec<(f32, f32, f32)>,
+    ) -> Vec<f32> {
         let mut b = f32::from(self.boost);
         let mut v = self.landing_velocity.dot(self.rotmat.x_axis);
+        let mut d = 0.;
         let mut fast_forward = false;
 
-        self.max_speed = Vec::with_capacity(max_ball_slice);
-        self.max_speed.push(v);
-
-        self.ctrms = Vec::with_capacity(max_ball_slice);
-        self.ctrms.push(turn_radius(v));
+        let mut max_speed = Vec::with_capacity(max_ball_slice);
+        max_speed.push(v);
 
         let end_1 = (self.time_to_land * 120.).round() as usize;
 
         for _ in 0..end_1 {
-            self.max_speed.push(v);
-            self.ctrms.push(turn_radius(v));
+            max_speed.push(v);
         }
 
-        let mut end = end_1;
-
-        if mutators.boost_amount != BoostAmount::NoBoost {
-            for _ in end_1..max_ball_slice {
-                end += 1;
+        let boost_accel = if mutators.boost_amount == BoostAmount::NoBoost {
+            0.
+        } else {
+            mutators.boost_accel
+        };
 
-                if fast_forward {
-                    self.max_speed.push(v);
-                    self.ctrms.push(turn_radius(v));
-                    continue;
-                }
+        for i in 0..max_ball_slice.saturating_sub(end_1) {
+            if fast_forward {
+                max_speed.push(v);
+                continue;
+            }
 
-                if b < BOOST_CONSUMPTION_DT {
+            // pick up every pad that we've driven over, if it's active by the time we get there
+            while let Some(&(distance_along, time_until_active, amount)) = pads_ahead.last() {
+                if d < distance_along {
                     break;
                 }
 
-                let throttle_accel = throttle_acceleration(v);
-                let mut accel = 0.;
-
-                if v.is_sign_positive() {
-                    accel += throttle_accel * SIMULATION_DT;
-                } else {
-                    accel += BRAKE_ACC_DT;
-                }
-
-                if b > BOOST_CONSUMPTION_DT {
-                    accel += mutators.boost_accel * SIMULATION_DT;
-                    if mutators.boost_amount != BoostAmount::Unlimited {
-                        b -= BOOST_CONSUMPTION_DT;
-                    }
-                }
-
-                accel = accel.min(MAX_SPEED - v);
-
-                if accel.abs() < f32::EPSILON {
-                    fast_forward = true;
+                if i as f32 * SIMULATION_DT >= time_until_active {
+                    b = (b + amount).min(100.);
                 }
 
-                v += accel;
-
-                self.max_speed.push(v);
-                self.ctrms.push(turn_radius(v));
+                pads_ahead.pop();
             }
 
-            if end == max_ball_slice {
-                return;
-            }
-        }
-
-        let mut v1 = v;
-        let mut v2 = v;
-
-        for _ in end..max_ball_slice {
-            if fast_forward {
-                self.max_speed.push(v1);
-                continue;
-            }
-
-            let throttle_accel = throttle_acceleration(v1);
+            let throttle_accel = throttle_acceleration(v);
             let mut accel = 0.;
 
-            if (1. - v1.signum()).abs() < f32::EPSILON {
+            if v.is_sign_positive() {
                 accel += throttle_accel * SIMULATION_DT;
             } else {
                 accel += BRAKE_ACC_DT;
             }
 
-            accel = accel.min(MAX_SPEED - v1);
-
-            if accel.abs() < f32::EPSILON {
-                fast_forward = true;
-            }
-
-            v1 += accel;
-
-            self.max_speed.push(v1);
-        }
-
-        for _ in end..max_ball_slice {
-            if fast_forward {
-                self.ctrms.push(turn_radius(v2));
-                continue;
-            }
-
-            let throttle_accel = throttle_acceleration(v2);
-            let mut accel = 0.;
+            let can_boost = boost_accel != 0. && b > BOOST_CONSUMPTION_DT;
 
-            if (1. - v2.signum()).abs() < f32::EPSILON {
-                accel += throttle_accel * SIMULATION_DT;
-            } else {
-                accel += BRAKE_ACC_DT;
+            if can_boost {
+                accel += boost_accel * SIMULATION_DT;
+                if mutators.boost_amount != BoostAmount::Unlimited {
+                    b -= BOOST_CONSUMPTION_DT;
+                }
             }
 
-            accel = accel.min(MAX_SPEED - v2);
+            accel = accel.min(MAX_SPEED - v);
 
-            if accel.abs() < f32::EPSILON {
+            // we'll stay at this speed forever if we can't get any more boost
+            if accel.abs() < f32::EPSILON && (can_boost || pads_ahead.is_empty()) {
                 fast_forward = true;
             }
 
-            v2 += accel;
+            v += accel;
+            d += v * SIMULATION_DT;
 
-            self.ctrms.push(turn_radius(v2));
+            max_speed.push(v);
         }
+
+        max_speed
     }
 
     fn calculate_local_values(&mut self) {
         self.local_velocity = self.localize(self.velocity);
     }
 
-    fn calculate_field(&mut self) {
-        self.field = FieldRect::from(&self.hitbox);
+    fn calculate_field(&mut self, field: Option<Arc<FieldGrid>>, arena: Arena) {
+        self.field = FieldRect::from(&self.hitbox, field, arena.walls());
     }
 
     #[inline]
@@ -663,11 +866,49 @@ impl Car {
     }
 }
 
+/// The Octane's hitbox, which the tests use for every car
+#[cfg(test)]
+pub const OCTANE_HITBOX: Hitbox = Hitbox {
+    length: 118.,
+    width: 84.2,
+    height: 36.2,
+};
+
+#[cfg(test)]
+pub const OCTANE_HITBOX_OFFSET: Vec3A = Vec3A::new(13.9, 0., 20.8);
+
+/// A car with the Octane's hitbox that's sitting on the ground at `location`, facing along `yaw`
+///
+/// The car still has to be initialized
+#[cfg(test)]
+#[must_use]
+pub fn grounded_test_car(location: Vec3A, yaw: f32, boost: u8) -> Car {
+    let mut car = Car::new();
+
+    car.location = location;
+    car.yaw = yaw;
+    car.hitbox = OCTANE_HITBOX;
+    car.hitbox_offset = OCTANE_HITBOX_OFFSET;
+    car.boost = boost;
+    car.car_state = State::Grounded;
+
+    car
+}
+
 #[cfg(test)]
 mod tests {
+    use std::{f32::consts::FRAC_PI_2, sync::Arc};
+
+    use glam::Vec3A;
+
     use crate::{
-        car::{Car, Hitbox, State},
-        Mutators, Vec3A,
+        boost::standard_boost_pads,
+        car::{
+            grounded_test_car, max_turn_speed, turn_radius, Arena, Car, FieldRect, Hitbox, Obstacle, State, OCTANE_HITBOX,
+        },
+        constants::MAX_SPEED,
+        field::FieldGrid,
+        Mutators,
     };
 
     #[test]
@@ -690,6 +931,126 @@ mod tests {
         car.boost = 48;
         car.car_state = State::Grounded;
 
-        car.init(-650., 720, Mutators::new());
+        car.init(-650., 720, Mutators::new(), &[], None, Arena::Soccar);
+    }
+
+    #[test]
+    pub fn boost_pad_pickup() {
+        let mut car = grounded_test_car(Vec3A::new(0., -4500., 17.), FRAC_PI_2, 0);
+
+        car.init(-650., 720, Mutators::new(), &[], None, Arena::Soccar);
+        let no_pads_speed = *car.max_speed.last().unwrap();
+        let no_pads_ctrms = car.ctrms.clone();
+
+        car.init = false;
+        car.init(-650., 720, Mutators::new(), &standard_boost_pads(), None, Arena::Soccar);
+        let pads_speed = *car.max_speed.last().unwrap();
+
+        // driving straight up the middle picks up the small pads in front of the goal
+        assert!(pads_speed > no_pads_speed);
+        assert!(pads_speed <= MAX_SPEED);
+
+        // but paths that turn away from the pads can't count on them
+        assert_eq!(car.ctrms, no_pads_ctrms);
+        assert_eq!(car.ctrms.len(), car.max_speed.len());
+    }
+
+    #[test]
+    pub fn field_rect_arenas() {
+        let hitbox = OCTANE_HITBOX;
+
+        let field_rect = |(game, ball), arena: Arena| {
+            FieldRect::from(&hitbox, Some(Arc::new(FieldGrid::from_game(&game, ball))), arena.walls())
+        };
+
+        let soccar = field_rect(rl_ball_sym::compressed::load_standard(), Arena::Soccar);
+        assert!(soccar.is_point_in(Vec3A::new(3000., 4000., 0.)));
+        assert!(soccar.is_point_in(Vec3A::new(0., 5300., 0.)));
+        assert!(!soccar.is_point_in(Vec3A::new(1500., 5500., 0.)));
+        // the rounded corners
+        assert!(!soccar.is_point_in(Vec3A::new(3700., 4700., 0.)));
+
+        // a ball resting against the side wall, 1000uu up
+        let ball_location = Vec3A::new(4096. - 93., 1000., 1000.);
+        let wall = soccar.find_wall(ball_location, 250.).unwrap();
+        let unfolded = wall.unfold(ball_location);
+        assert!((unfolded - Vec3A::new(5096., 1000., 93.)).length() < 0.01);
+        assert!((wall.fold(unfolded) - ball_location).length() < 0.01);
+        assert!(soccar.is_point_on(unfolded, Some(wall)));
+        assert!(!soccar.is_point_in(unfolded));
+        // balls in front of the goal or on the floor aren't on a wall
+        assert!(soccar.find_wall(Vec3A::new(0., 5120. - 93., 300.), 250.).is_none());
+        assert!(soccar.find_wall(Vec3A::new(3900., 0., 93.), 250.).is_none());
+
+        let hoops = field_rect(rl_ball_sym::compressed::load_hoops(), Arena::Hoops);
+        assert!(hoops.is_point_in(Vec3A::new(1500., 0., 0.)));
+        assert!(!hoops.is_point_in(Vec3A::new(0., 4000., 0.)));
+        assert!(!hoops.is_point_in(Vec3A::new(2800., 3400., 0.)));
+
+        let dropshot = field_rect(rl_ball_sym::compressed::load_dropshot(), Arena::Dropshot);
+        assert!(dropshot.is_point_in(Vec3A::new(0., 3900., 0.)));
+        assert!(!dropshot.is_point_in(Vec3A::new(0., 4500., 0.)));
+        assert!(!dropshot.is_point_in(Vec3A::new(3500., 3500., 0.)));
+    }
+
+    #[test]
+    pub fn max_turn_speed_inverts_turn_radius() {
+        for v in [0., 250., 900., 1250., 1600., 2000., 2295.] {
+            assert!((max_turn_speed(turn_radius(v)) - v).abs() < 1.);
+        }
+
+        // tighter than the car can ever turn, and wider than it needs to
+        assert_eq!(max_turn_speed(100.), 0.);
+        assert_eq!(max_turn_speed(5000.), 2295.);
+    }
+
+    #[test]
+    pub fn goal_posts() {
+        // blue shoots at the orange goal, facing +y, which puts +x on the left
+        let (left, right) = Arena::Soccar.goal_posts(1).unwrap();
+        assert!(left.x > 0. && right.x < 0.);
+        assert!(left.y > 5000. && right.y > 5000.);
+
+        let (left, right) = Arena::Soccar.goal_posts(0).unwrap();
+        assert!(left.x < 0. && right.x > 0.);
+        assert!(left.y < -5000. && right.y < -5000.);
+
+        // throwback's goals are closer to the middle of the field, and wider
+        let (left, right) = Arena::Throwback.goal_posts(1).unwrap();
+        assert!((left.y - 4900.).abs() < f32::EPSILON && (right.y - 4900.).abs() < f32::EPSILON);
+        assert!(left.x > Arena::Soccar.goal_posts(1).unwrap().0.x);
+
+        assert!(Arena::Hoops.goal_posts(0).is_none());
+        assert!(Arena::Dropshot.goal_posts(0).is_none());
+    }
+
+    #[test]
+    pub fn obstacle_blocks() {
+        let gravity = Vec3A::new(0., 0., -650.);
+        let hitbox = OCTANE_HITBOX;
+
+        let mut car = Car::new();
+        car.hitbox = hitbox;
+
+        let mut other = Car::new();
+        other.hitbox = hitbox;
+        other.location = Vec3A::new(0., 0., 17.);
+        other.velocity = Vec3A::new(1000., 0., 0.);
+        other.car_state = State::Grounded;
+
+        // a car on the ground keeps driving the way that it is
+        let obstacle = Obstacle::from(&car, &other);
+        assert!((obstacle.location_at(1., gravity) - Vec3A::new(1000., 0., 17.)).length() < 0.01);
+        assert!(obstacle.blocks(Vec3A::new(1000., 100., 17.), 1., gravity));
+        assert!(!obstacle.blocks(Vec3A::new(1000., 120., 17.), 1., gravity));
+        assert!(!obstacle.blocks(Vec3A::new(0., 0., 17.), 1., gravity));
+
+        // a car in the air falls back down to the ground
+        other.location.z = 500.;
+        other.car_state = State::Jumped;
+        let obstacle = Obstacle::from(&car, &other);
+        assert!((obstacle.location_at(0.5, gravity).z - (500. - 650. * 0.125)).abs() < 0.01);
+        assert!((obstacle.location_at(2., gravity) - Vec3A::new(2000., 0., 17.)).length() < 0.01);
+        assert!(obstacle.blocks(Vec3A::new(2000., 0., 17.), 2., gravity));
     }
 }
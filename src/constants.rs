@@ -1,3 +1,4 @@
+#[cfg(feature = "python")]
 use pyo3::exceptions;
 
 pub const MAX_SPEED: f32 = 2300.;
@@ -29,52 +30,87 @@ pub const COASTING_THROTTLE_TRANSITION: f32 = -0.5 * COAST_ACC;
 pub const REACTION_TIME: f32 = 0.04;
 pub const STEER_REACTION_TIME: f32 = 0.25;
 
+/// How many times tighter the car can turn while powersliding than it can by only steering
+pub const POWERSLIDE_TURN_SCALE: f32 = 2.;
+/// How quickly the car loses speed while it's powersliding
+pub const POWERSLIDE_DECEL: f32 = 800.;
+/// Powersliding any slower than this only loses speed
+pub const MIN_POWERSLIDE_SPEED: f32 = 500.;
+/// The speed that the car changes direction the quickest at while only steering
+pub const QUICK_TURN_SPEED: f32 = 900.;
+
 pub const JUMP_MAX_DURATION: f32 = 0.2;
 pub const JUMP_SPEED: f32 = 291. + (2. / 3.);
 pub const JUMP_ACC: f32 = 1458. + (1. / 3.);
 pub const DOUBLE_JUMP_DURATION: f32 = JUMP_MAX_DURATION + SIMULATION_DT * 2.;
+
+/// Angular acceleration from full roll, pitch, and yaw input, around the car's local axes
+pub const ROLL_TORQUE: f32 = -36.079_567;
+pub const PITCH_TORQUE: f32 = -12.145_998;
+pub const YAW_TORQUE: f32 = 8.919_628;
+/// Angular damping in the air, which only applies to pitch and yaw while there's no input on them
+pub const ROLL_DAMPING: f32 = -4.471_663;
+pub const PITCH_DAMPING: f32 = -2.798_194;
+pub const YAW_DAMPING: f32 = -1.886_492;
+pub const MAX_ANGULAR_SPEED: f32 = 5.5;
 pub const ON_GROUND_WAIT_TIME: f32 = 0.6;
+/// The speed that a forwards dodge adds to the car
+pub const DODGE_IMPULSE: f32 = 500.;
+/// Jump has to be released for a tick before it can be pressed again to dodge
+pub const MIN_DODGE_DELAY: f32 = SIMULATION_DT * 3.;
+/// How long after the start of the dodge the car reaches the ball
+pub const DODGE_TO_HIT_TIME: f32 = 0.1;
+
+pub const CAR_MASS: f32 = 180.;
+pub const BALL_MASS: f32 = 30.;
+pub const BALL_MAX_SPEED: f32 = 6000.;
 
+#[cfg(feature = "python")]
 pub type NoGamePyErr = exceptions::PyNameError;
 pub const NO_GAME_ERR: &str = "GAME is unset. Call a function like load_soccar first.";
+#[cfg(feature = "python")]
 pub type NoCarPyErr = exceptions::PyIndexError;
 pub const NO_CAR_ERR: &str = "No car at the provided index.";
+#[cfg(feature = "python")]
 pub type NoSlicesPyErr = exceptions::PyValueError;
 pub const NO_SLICES_ERR: &str =
     "Ball prediction struct has not been initialized yet. Try calling a function like tick() first.";
+#[cfg(feature = "python")]
 pub type NoTargetPyErr = exceptions::PyIndexError;
 pub const NO_TARGET_ERR: &str = "Target no longer exists.";
+#[cfg(feature = "python")]
 pub type NoShotPyErr = exceptions::PyLookupError;
 pub const NO_SHOT_ERR: &str = "Specified target has no found shot.";
+#[cfg(feature = "python")]
 pub type BallChangedPyErr = exceptions::PyAssertionError;
 pub const BALL_CHANGED_ERR: &str = "Ball has changed too much from the original prediction.";
+#[cfg(feature = "python")]
 pub type NoShotSelectedPyErr = exceptions::PyAssertionError;
 pub const NO_SHOT_SELECTED_ERR: &str = "All shots were disabled.";
+#[cfg(feature = "python")]
 pub type NoTimeRemainingPyErr = exceptions::PyValueError;
 pub const NO_TIME_REMAINING_ERR: &str = "Time expired for the shot.";
+#[cfg(feature = "python")]
 pub type BadAccelerationPyErr = exceptions::PyAssertionError;
 pub const BAD_ACCELERATION_ERR: &str = "Acceleration is slower than expected.";
+#[cfg(feature = "python")]
 pub type StrayedFromPathPyErr = exceptions::PyAssertionError;
 pub const STRAYED_FROM_PATH_ERR: &str = "Car has strayed from the path.";
+#[cfg(feature = "python")]
+pub type NoGoalsPyErr = exceptions::PyValueError;
+pub const NO_GOALS_ERR: &str = "The loaded arena has no goals with posts.";
+#[cfg(feature = "python")]
+pub type CarGonePyErr = exceptions::PyLookupError;
+pub const CAR_GONE_ERR: &str = "The target's car has left the game.";
+
+#[cfg(feature = "python")]
+pub type NoBoostPadsPyErr = exceptions::PyLookupError;
+pub const NO_BOOST_PADS_ERR: &str = "The boost pad layout of the loaded arena isn't known. Call set_field_info first.";
+#[cfg(feature = "python")]
+pub type InvalidFlatbufferPyErr = exceptions::PyValueError;
+pub const INVALID_FLATBUFFER_ERR: &str = "The bytes aren't a valid RLBot v5 flatbuffer.";
 
 pub const STICKY_FORCE: f32 = -325.;
 pub const STICKY_TIMER: f32 = SIMULATION_DT * 3.;
 pub const HOLD_BONUS: f32 = 292. * 5.;
 pub const MAX_HOLD_TIME: f32 = 0.2;
-
-// magic numbers from the gods themselves
-// aka scipy.optimize.curve_fit
-pub const TURN_TIME_POLY_EST: [f64; 12] = [
-    -1.87039867e-03,
-    2.89665376e-02,
-    -1.79716410e-01,
-    5.88462548e-01,
-    -1.10170154e+00,
-    1.30438381e+00,
-    2.31720904e-02,
-    3.43119958e-10,
-    8.33333398e-03,
-    -1.00249604e+01,
-    4.97746568e+00,
-    -3.68316625e-01,
-];
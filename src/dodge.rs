@@ -0,0 +1,80 @@
+use glam::Vec3A;
+use rl_ball_sym::simulation::ball::Ball;
+
+use crate::{
+    car::Car,
+    constants::*,
+    hit::{rotmat_from, Contact},
+    utils::flatten,
+};
+
+/// A dodge into the ball at the end of a jump shot
+#[derive(Clone, Copy, Debug)]
+pub struct Dodge {
+    /// The time after the start of the jump to dodge
+    pub time: f32,
+    /// The direction to dodge in, in the ground plane
+    pub direction: Vec3A,
+    /// The estimated velocity of the ball right after the car hits it
+    pub ball_velocity: Vec3A,
+}
+
+impl Dodge {
+    /// Plan a forwards dodge `jump_time` after jumping,
+    /// for a car that will be driving at `car_speed` along the `shot_vector` before it jumps
+    #[must_use]
+    pub fn new(jump_time: f32, ball: &Ball, shot_vector: Vec3A, car_speed: f32, car: &Car) -> Self {
+        let direction = flatten(shot_vector).normalize_or_zero();
+
+        // the dodge stops the car from falling, so it will be level when it hits the ball
+        let contact = Contact::new(
+            ball,
+            shot_vector,
+            rotmat_from(direction, Vec3A::Z),
+            direction * (car_speed + DODGE_IMPULSE).min(MAX_SPEED),
+            car,
+        );
+
+        Self {
+            time: jump_time - DODGE_TO_HIT_TIME,
+            direction,
+            ball_velocity: contact.hit(ball, car),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3A;
+
+    use crate::{
+        car::grounded_test_car,
+        constants::DODGE_TO_HIT_TIME,
+        dodge::Dodge,
+        hit::{rotmat_from, Contact},
+    };
+
+    #[test]
+    pub fn dodge_hits_harder() {
+        let car = grounded_test_car(Vec3A::ZERO, 0., 0);
+
+        let (_, mut ball) = rl_ball_sym::compressed::load_standard();
+        ball.update(0., Vec3A::new(0., 0., 150.), Vec3A::ZERO, Vec3A::ZERO);
+
+        let shot_vector = Vec3A::new(0., 1., 0.2).normalize();
+        let dodge = Dodge::new(0.4, &ball, shot_vector, 1000., &car);
+
+        // the dodge starts just before the car gets to the ball, straight towards the target
+        assert!((dodge.time - (0.4 - DODGE_TO_HIT_TIME)).abs() < f32::EPSILON);
+        assert!((dodge.direction - Vec3A::Y).length() < 0.001);
+
+        // and sends the ball faster than just driving into it would
+        let direction = Vec3A::Y;
+        let no_dodge = Contact::new(&ball, shot_vector, rotmat_from(direction, Vec3A::Z), direction * 1000., &car);
+        let no_dodge_velocity = no_dodge.hit(&ball, &car);
+
+        assert!(dodge.ball_velocity.y > no_dodge_velocity.y);
+        // the ball comes off faster than the car is going after the dodge
+        assert!(dodge.ball_velocity.y > 1500.);
+    }
+}
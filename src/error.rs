@@ -0,0 +1,61 @@
+use thiserror::Error;
+
+use crate::constants::*;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Error)]
+pub enum RlibError {
+    #[error("{}", NO_GAME_ERR)]
+    NoGame,
+    #[error("{}", NO_CAR_ERR)]
+    NoCar,
+    #[error("{}", NO_SLICES_ERR)]
+    NoSlices,
+    #[error("{}", NO_TARGET_ERR)]
+    NoTarget,
+    #[error("{}", NO_SHOT_ERR)]
+    NoShot,
+    #[error("{}", BALL_CHANGED_ERR)]
+    BallChanged,
+    #[error("{}", NO_SHOT_SELECTED_ERR)]
+    NoShotSelected,
+    #[error("{}", NO_TIME_REMAINING_ERR)]
+    NoTimeRemaining,
+    #[error("{}", BAD_ACCELERATION_ERR)]
+    BadAcceleration,
+    #[error("{}", STRAYED_FROM_PATH_ERR)]
+    StrayedFromPath,
+    #[error("{}", NO_GOALS_ERR)]
+    NoGoals,
+    #[error("{}", CAR_GONE_ERR)]
+    CarGone,
+    #[error("{}", NO_BOOST_PADS_ERR)]
+    NoBoostPads,
+    #[error("{}", INVALID_FLATBUFFER_ERR)]
+    InvalidFlatbuffer,
+}
+
+pub type RlibResult<T> = Result<T, RlibError>;
+
+#[cfg(feature = "python")]
+impl From<RlibError> for pyo3::PyErr {
+    fn from(err: RlibError) -> Self {
+        let msg = err.to_string();
+
+        match err {
+            RlibError::NoGame => NoGamePyErr::new_err(msg),
+            RlibError::NoCar => NoCarPyErr::new_err(msg),
+            RlibError::NoSlices => NoSlicesPyErr::new_err(msg),
+            RlibError::NoTarget => NoTargetPyErr::new_err(msg),
+            RlibError::NoShot => NoShotPyErr::new_err(msg),
+            RlibError::BallChanged => BallChangedPyErr::new_err(msg),
+            RlibError::NoShotSelected => NoShotSelectedPyErr::new_err(msg),
+            RlibError::NoTimeRemaining => NoTimeRemainingPyErr::new_err(msg),
+            RlibError::BadAcceleration => BadAccelerationPyErr::new_err(msg),
+            RlibError::StrayedFromPath => StrayedFromPathPyErr::new_err(msg),
+            RlibError::NoGoals => NoGoalsPyErr::new_err(msg),
+            RlibError::CarGone => CarGonePyErr::new_err(msg),
+            RlibError::NoBoostPads => NoBoostPadsPyErr::new_err(msg),
+            RlibError::InvalidFlatbuffer => InvalidFlatbufferPyErr::new_err(msg),
+        }
+    }
+}
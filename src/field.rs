@@ -0,0 +1,191 @@
+use std::collections::VecDeque;
+
+use glam::Vec3A;
+use rl_ball_sym::simulation::{ball::Ball, game::Game};
+
+use crate::constants::SIMULATION_DT;
+
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum Cell {
+    Unknown,
+    Queued,
+    Floor,
+    Wall,
+}
+
+/// The distance from points on the floor to the nearest wall, goal post, or other obstacle
+///
+/// Built by probing the collision mesh that `rl_ball_sym` loaded with a small ball,
+/// so it matches whichever arena was loaded, rounded corners included
+#[derive(Clone, Debug)]
+pub struct FieldGrid {
+    width: usize,
+    height: usize,
+    clearance: Vec<f32>,
+}
+
+impl FieldGrid {
+    const CELL_SIZE: f32 = 50.;
+    /// Large enough to cover the floor of every arena, including the goals
+    const HALF_WIDTH: f32 = 6000.;
+    const HALF_HEIGHT: f32 = 8000.;
+    /// Big enough that the probes overlap, so thin walls can't slip between them
+    const PROBE_RADIUS: f32 = Self::CELL_SIZE * 0.75;
+    const PROBE_SPEED: f32 = 500.;
+
+    #[must_use]
+    pub fn from_game(game: &Game, mut ball: Ball) -> Self {
+        ball.set_radius(Self::PROBE_RADIUS, Self::PROBE_RADIUS);
+
+        let width = (Self::HALF_WIDTH * 2. / Self::CELL_SIZE) as usize;
+        let height = (Self::HALF_HEIGHT * 2. / Self::CELL_SIZE) as usize;
+
+        // flood fill outwards from the center of the field until a wall is hit in every direction
+        let mut cells = vec![Cell::Unknown; width * height];
+        let mut queue = VecDeque::new();
+
+        let start = width / 2 + height / 2 * width;
+        cells[start] = Cell::Queued;
+        queue.push_back(start);
+
+        while let Some(i) = queue.pop_front() {
+            let (x, y) = (i % width, i / width);
+
+            if x == 0 || y == 0 || x == width - 1 || y == height - 1 || Self::probe(game, ball, Self::cell_center(x, y)) {
+                cells[i] = Cell::Wall;
+                continue;
+            }
+
+            cells[i] = Cell::Floor;
+
+            for neighbor in [i - 1, i + 1, i - width, i + width] {
+                if cells[neighbor] == Cell::Unknown {
+                    cells[neighbor] = Cell::Queued;
+                    queue.push_back(neighbor);
+                }
+            }
+        }
+
+        // everything that wasn't reached is outside of the field
+        let mut clearance: Vec<f32> = cells
+            .into_iter()
+            .map(|cell| if cell == Cell::Floor { f32::INFINITY } else { 0. })
+            .collect();
+
+        Self::distance_transform(&mut clearance, width, height);
+
+        Self {
+            width,
+            height,
+            clearance,
+        }
+    }
+
+    #[inline]
+    fn cell_center(x: usize, y: usize) -> Vec3A {
+        Vec3A::new(
+            (x as f32 + 0.5) * Self::CELL_SIZE - Self::HALF_WIDTH,
+            (y as f32 + 0.5) * Self::CELL_SIZE - Self::HALF_HEIGHT,
+            0.,
+        )
+    }
+
+    /// Checks if a ball rolling through the point in any direction would hit something
+    fn probe(game: &Game, mut ball: Ball, location: Vec3A) -> bool {
+        let location = location + Vec3A::Z * (Self::PROBE_RADIUS + 5.);
+
+        [Vec3A::X, Vec3A::Y, Vec3A::NEG_X, Vec3A::NEG_Y].into_iter().any(|direction| {
+            let velocity = direction * Self::PROBE_SPEED;
+            ball.update(0., location, velocity, Vec3A::ZERO);
+            ball.step(game, SIMULATION_DT);
+
+            let expected_location = location + velocity * SIMULATION_DT;
+
+            // if the ball was pushed out by more than its radius, it hit the back of a surface,
+            // which can't happen from inside of the field
+            ball.velocity.dot(direction) < Self::PROBE_SPEED - 1.
+                && ball.location.distance(expected_location) < Self::PROBE_RADIUS
+        })
+    }
+
+    /// Turns a grid of zeros (walls) and infinities (floor) into the distance to the nearest wall
+    fn distance_transform(grid: &mut [f32], width: usize, height: usize) {
+        let straight = Self::CELL_SIZE;
+        let diagonal = Self::CELL_SIZE * std::f32::consts::SQRT_2;
+
+        for y in 1..height {
+            for x in 1..width - 1 {
+                let i = x + y * width;
+                grid[i] = grid[i]
+                    .min(grid[i - 1] + straight)
+                    .min(grid[i - width] + straight)
+                    .min(grid[i - width - 1] + diagonal)
+                    .min(grid[i - width + 1] + diagonal);
+            }
+        }
+
+        for y in (0..height - 1).rev() {
+            for x in (1..width - 1).rev() {
+                let i = x + y * width;
+                grid[i] = grid[i]
+                    .min(grid[i + 1] + straight)
+                    .min(grid[i + width] + straight)
+                    .min(grid[i + width + 1] + diagonal)
+                    .min(grid[i + width - 1] + diagonal);
+            }
+        }
+    }
+
+    /// The distance from the point to the nearest wall, or 0 if it's outside of the field
+    #[must_use]
+    pub fn clearance(&self, p: Vec3A) -> f32 {
+        // position relative to the first cell center, in cells
+        let gx = (p.x + Self::HALF_WIDTH) / Self::CELL_SIZE - 0.5;
+        let gy = (p.y + Self::HALF_HEIGHT) / Self::CELL_SIZE - 0.5;
+
+        if gx < 0. || gy < 0. || gx >= (self.width - 1) as f32 || gy >= (self.height - 1) as f32 {
+            return 0.;
+        }
+
+        let (x, y) = (gx as usize, gy as usize);
+        let (tx, ty) = (gx.fract(), gy.fract());
+        let i = x + y * self.width;
+
+        let bottom = self.clearance[i] * (1. - tx) + self.clearance[i + 1] * tx;
+        let top = self.clearance[i + self.width] * (1. - tx) + self.clearance[i + self.width + 1] * tx;
+
+        bottom * (1. - ty) + top * ty
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3A;
+
+    use crate::field::FieldGrid;
+
+    #[test]
+    pub fn standard_clearance() {
+        let (game, ball) = rl_ball_sym::compressed::load_standard();
+        let grid = FieldGrid::from_game(&game, ball);
+
+        // the side walls are the closest to the center of the field,
+        // but the probes hit the curve up the wall before they get to the wall itself
+        let center = grid.clearance(Vec3A::ZERO);
+        assert!(center > 3800. && center < 4096.);
+        assert!((grid.clearance(Vec3A::new(2000., 0., 0.)) - (center - 2000.)).abs() < FieldGrid::CELL_SIZE);
+
+        // the goal posts are closer than the back wall in front of the goal
+        let post_distance = Vec3A::new(0., 4000., 0.).distance(Vec3A::new(893., 5120., 0.));
+        assert!((grid.clearance(Vec3A::new(0., 4000., 0.)) - post_distance).abs() < FieldGrid::CELL_SIZE);
+
+        // the flood fill reaches into the goals, but not past the walls
+        assert!(grid.clearance(Vec3A::new(0., 5400., 0.)) > 0.);
+        assert_eq!(grid.clearance(Vec3A::new(4500., 0., 0.)), 0.);
+        assert_eq!(grid.clearance(Vec3A::new(0., 6500., 0.)), 0.);
+        assert_eq!(grid.clearance(Vec3A::new(2000., 5400., 0.)), 0.);
+
+        // and everything off of the grid is outside of the field
+        assert_eq!(grid.clearance(Vec3A::new(0., 9000., 0.)), 0.);
+    }
+}
@@ -0,0 +1,767 @@
+//! Reads RLBot v5's flatbuffer `GamePacket`, `FieldInfo` and `MatchConfiguration` straight from their bytes
+//!
+//! The tables are read in place with bounds-checked reads instead of generated code,
+//! so only the fields that the library uses are looked at.
+//! The field order follows RLBot v5's `gamedata.fbs` and `matchconfig.fbs` schemas.
+
+use crate::{
+    constants::BOOST_ACCEL,
+    error::{RlibError, RlibResult},
+    pytypes::{
+        GameBall, GameBoost, GameBoostPad, GameBox, GameCar, GameCollisionShape, GameCylinder, GameFieldInfo, GameInfo,
+        GamePacket, GamePhysics, GameRot, GameScoreInfo, GameSphere, GameVec, Hitbox,
+    },
+    BoostAmount, Mutators,
+};
+
+/// The index of each field that's read, in the order that the schema declares them
+mod game_packet {
+    pub const PLAYERS: usize = 0;
+    pub const BOOST_PADS: usize = 1;
+    pub const BALLS: usize = 2;
+    pub const MATCH_INFO: usize = 3;
+}
+
+mod player_info {
+    pub const PHYSICS: usize = 0;
+    pub const SCORE_INFO: usize = 1;
+    pub const HITBOX: usize = 2;
+    pub const HITBOX_OFFSET: usize = 3;
+    pub const AIR_STATE: usize = 5;
+    pub const DEMOLISHED_TIMEOUT: usize = 7;
+    pub const IS_SUPERSONIC: usize = 8;
+    pub const IS_BOT: usize = 9;
+    pub const NAME: usize = 10;
+    pub const TEAM: usize = 11;
+    pub const BOOST: usize = 12;
+    pub const PLAYER_ID: usize = 13;
+    pub const HAS_JUMPED: usize = 17;
+    pub const HAS_DOUBLE_JUMPED: usize = 18;
+    pub const HAS_DODGED: usize = 19;
+}
+
+mod ball_info {
+    pub const PHYSICS: usize = 0;
+    /// Unions take up two fields, the type of the value and then the value
+    pub const SHAPE_TYPE: usize = 1;
+    pub const SHAPE: usize = 2;
+}
+
+mod match_info {
+    pub const SECONDS_ELAPSED: usize = 0;
+    pub const GAME_TIME_REMAINING: usize = 1;
+    pub const IS_OVERTIME: usize = 2;
+    pub const MATCH_PHASE: usize = 4;
+    pub const WORLD_GRAVITY_Z: usize = 5;
+}
+
+mod field_info {
+    pub const BOOST_PADS: usize = 0;
+}
+
+mod match_configuration {
+    pub const MUTATORS: usize = 10;
+}
+
+mod mutator_settings {
+    pub const BOOST_AMOUNT: usize = 11;
+    pub const BOOST_STRENGTH: usize = 13;
+}
+
+/// The sizes of the structs that are read, which are packed the same way that C would pack them
+const VECTOR3_SIZE: usize = 12;
+const BOOST_PAD_STATE_SIZE: usize = 8;
+const BOOST_PAD_SIZE: usize = 16;
+
+/// `AirState::OnGround`
+const ON_GROUND: u8 = 0;
+
+/// `CollisionShape`'s union types, which start at 1 because 0 means that there's no shape
+const BOX_SHAPE: u8 = 1;
+const SPHERE_SHAPE: u8 = 2;
+const CYLINDER_SHAPE: u8 = 3;
+
+/// The parts of the match that `MatchPhase` tells apart
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+enum MatchPhase {
+    Inactive,
+    Countdown,
+    Kickoff,
+    Active,
+    GoalScored,
+    Replay,
+    Paused,
+    Ended,
+}
+
+impl From<u8> for MatchPhase {
+    #[inline]
+    fn from(item: u8) -> Self {
+        match item {
+            1 => Self::Countdown,
+            2 => Self::Kickoff,
+            3 => Self::Active,
+            4 => Self::GoalScored,
+            5 => Self::Replay,
+            6 => Self::Paused,
+            7 => Self::Ended,
+            _ => Self::Inactive,
+        }
+    }
+}
+
+fn read<const N: usize>(buf: &[u8], pos: usize) -> RlibResult<[u8; N]> {
+    pos.checked_add(N)
+        .and_then(|end| buf.get(pos..end))
+        .and_then(|bytes| bytes.try_into().ok())
+        .ok_or(RlibError::InvalidFlatbuffer)
+}
+
+#[inline]
+fn read_u16(buf: &[u8], pos: usize) -> RlibResult<u16> {
+    read(buf, pos).map(u16::from_le_bytes)
+}
+
+#[inline]
+fn read_u32(buf: &[u8], pos: usize) -> RlibResult<u32> {
+    read(buf, pos).map(u32::from_le_bytes)
+}
+
+#[inline]
+fn read_i32(buf: &[u8], pos: usize) -> RlibResult<i32> {
+    read(buf, pos).map(i32::from_le_bytes)
+}
+
+#[inline]
+fn read_f32(buf: &[u8], pos: usize) -> RlibResult<f32> {
+    read(buf, pos).map(f32::from_le_bytes)
+}
+
+/// Follows the offset at `pos` to what it points to
+#[inline]
+fn follow(buf: &[u8], pos: usize) -> RlibResult<usize> {
+    pos.checked_add(read_u32(buf, pos)? as usize)
+        .ok_or(RlibError::InvalidFlatbuffer)
+}
+
+fn read_vec3(buf: &[u8], pos: usize) -> RlibResult<GameVec> {
+    Ok(GameVec {
+        x: read_f32(buf, pos)?,
+        y: read_f32(buf, pos + 4)?,
+        z: read_f32(buf, pos + 8)?,
+    })
+}
+
+fn read_physics(buf: &[u8], pos: usize) -> RlibResult<GamePhysics> {
+    let rotation = read_vec3(buf, pos + VECTOR3_SIZE)?;
+
+    Ok(GamePhysics {
+        location: read_vec3(buf, pos)?,
+        rotation: GameRot {
+            pitch: rotation.x,
+            yaw: rotation.y,
+            roll: rotation.z,
+        },
+        velocity: read_vec3(buf, pos + VECTOR3_SIZE * 2)?,
+        angular_velocity: read_vec3(buf, pos + VECTOR3_SIZE * 3)?,
+    })
+}
+
+fn read_score_info(buf: &[u8], pos: usize) -> RlibResult<GameScoreInfo> {
+    let stat = |i: usize| read_i32(buf, pos + i * 4);
+
+    Ok(GameScoreInfo {
+        score: stat(0)?,
+        goals: stat(1)?,
+        own_goals: stat(2)?,
+        assists: stat(3)?,
+        saves: stat(4)?,
+        shots: stat(5)?,
+        demolitions: stat(6)?,
+    })
+}
+
+/// A table somewhere in the buffer, and its vtable, which says where each of the table's fields are
+#[derive(Clone, Copy)]
+struct Table<'a> {
+    buf: &'a [u8],
+    pos: usize,
+    vtable: usize,
+    vtable_len: usize,
+}
+
+impl<'a> Table<'a> {
+    /// The table that the start of the buffer points to
+    fn root(buf: &'a [u8]) -> RlibResult<Self> {
+        Self::new(buf, follow(buf, 0)?)
+    }
+
+    fn new(buf: &'a [u8], pos: usize) -> RlibResult<Self> {
+        // the table starts with how far back its vtable is
+        let vtable = (pos as i64 - i64::from(read_i32(buf, pos)?))
+            .try_into()
+            .map_err(|_| RlibError::InvalidFlatbuffer)?;
+        let vtable_len = read_u16(buf, vtable)? as usize;
+
+        Ok(Self {
+            buf,
+            pos,
+            vtable,
+            vtable_len,
+        })
+    }
+
+    /// Where the field is in the buffer, or `None` if it was left out to use its default value
+    fn field(&self, index: usize) -> RlibResult<Option<usize>> {
+        // the vtable starts with its own length and the length of the table
+        let entry = 4 + index * 2;
+        if entry + 2 > self.vtable_len {
+            return Ok(None);
+        }
+
+        Ok(match read_u16(self.buf, self.vtable + entry)? {
+            0 => None,
+            offset => Some(self.pos + offset as usize),
+        })
+    }
+
+    fn f32(&self, index: usize) -> RlibResult<f32> {
+        self.f32_or(index, 0.)
+    }
+
+    fn f32_or(&self, index: usize, default: f32) -> RlibResult<f32> {
+        self.field(index)?.map_or(Ok(default), |pos| read_f32(self.buf, pos))
+    }
+
+    fn u32(&self, index: usize) -> RlibResult<u32> {
+        self.field(index)?.map_or(Ok(0), |pos| read_u32(self.buf, pos))
+    }
+
+    fn i32(&self, index: usize) -> RlibResult<i32> {
+        self.field(index)?.map_or(Ok(0), |pos| read_i32(self.buf, pos))
+    }
+
+    fn u8(&self, index: usize) -> RlibResult<u8> {
+        self.field(index)?
+            .map_or(Ok(0), |pos| read::<1>(self.buf, pos).map(|[byte]| byte))
+    }
+
+    fn bool(&self, index: usize) -> RlibResult<bool> {
+        self.u8(index).map(|byte| byte != 0)
+    }
+
+    fn string(&self, index: usize) -> RlibResult<String> {
+        let Some(pos) = self.field(index)? else {
+            return Ok(String::new());
+        };
+
+        let (start, len) = self.vector(follow(self.buf, pos)?, 1)?;
+        Ok(String::from_utf8_lossy(&self.buf[start..start + len]).into_owned())
+    }
+
+    fn table(&self, index: usize) -> RlibResult<Option<Table<'a>>> {
+        self.field(index)?
+            .map(|pos| Table::new(self.buf, follow(self.buf, pos)?))
+            .transpose()
+    }
+
+    /// The start and length of the vector at `pos`, after checking that all of it is in the buffer
+    fn vector(&self, pos: usize, item_size: usize) -> RlibResult<(usize, usize)> {
+        let len = read_u32(self.buf, pos)? as usize;
+        let start = pos + 4;
+
+        len.checked_mul(item_size)
+            .and_then(|size| start.checked_add(size))
+            .filter(|&end| end <= self.buf.len())
+            .ok_or(RlibError::InvalidFlatbuffer)?;
+
+        Ok((start, len))
+    }
+
+    /// Where each struct in the vector of structs is in the buffer
+    fn structs(&self, index: usize, size: usize) -> RlibResult<impl Iterator<Item = usize>> {
+        let (start, len) = match self.field(index)? {
+            Some(pos) => self.vector(follow(self.buf, pos)?, size)?,
+            None => (0, 0),
+        };
+
+        Ok((0..len).map(move |i| start + i * size))
+    }
+
+    fn tables(&self, index: usize) -> RlibResult<Vec<Table<'a>>> {
+        self.structs(index, 4)?
+            .map(|pos| Table::new(self.buf, follow(self.buf, pos)?))
+            .collect()
+    }
+}
+
+fn read_car(player: Table) -> RlibResult<GameCar> {
+    let buf = player.buf;
+
+    // the timeout is -1 while the car isn't demolished
+    let is_demolished = player.f32_or(player_info::DEMOLISHED_TIMEOUT, -1.)? >= 0.;
+    let hitbox = match player.field(player_info::HITBOX)? {
+        Some(pos) => read_vec3(buf, pos)?,
+        None => GameVec::default(),
+    };
+
+    Ok(GameCar {
+        physics: match player.field(player_info::PHYSICS)? {
+            Some(pos) => read_physics(buf, pos)?,
+            None => GamePhysics::default(),
+        },
+        score_info: match player.field(player_info::SCORE_INFO)? {
+            Some(pos) => read_score_info(buf, pos)?,
+            None => GameScoreInfo::new(),
+        },
+        hitbox: Hitbox {
+            length: hitbox.x,
+            width: hitbox.y,
+            height: hitbox.z,
+        },
+        hitbox_offset: match player.field(player_info::HITBOX_OFFSET)? {
+            Some(pos) => read_vec3(buf, pos)?,
+            None => GameVec::default(),
+        },
+        name: player.string(player_info::NAME)?,
+        spawn_id: player.i32(player_info::PLAYER_ID)?,
+        boost: player.f32(player_info::BOOST)?.round().clamp(0., 100.) as u8,
+        team: player.u32(player_info::TEAM)? as u8,
+        jumped: player.bool(player_info::HAS_JUMPED)?,
+        double_jumped: player.bool(player_info::HAS_DOUBLE_JUMPED)? || player.bool(player_info::HAS_DODGED)?,
+        is_demolished,
+        has_wheel_contact: player.u8(player_info::AIR_STATE)? == ON_GROUND,
+        is_super_sonic: player.bool(player_info::IS_SUPERSONIC)?,
+        is_bot: player.bool(player_info::IS_BOT)?,
+    })
+}
+
+fn read_ball(ball: Table) -> RlibResult<GameBall> {
+    let buf = ball.buf;
+    let shape = ball.field(ball_info::SHAPE)?.map(|pos| follow(buf, pos)).transpose()?;
+
+    let mut collision_shape = GameCollisionShape::default();
+    match (ball.u8(ball_info::SHAPE_TYPE)?, shape) {
+        (BOX_SHAPE, Some(pos)) => {
+            let size = read_vec3(buf, pos)?;
+            collision_shape.shape_type = 0;
+            collision_shape.box_ = GameBox {
+                length: size.x,
+                width: size.y,
+                height: size.z,
+            };
+        }
+        (SPHERE_SHAPE, Some(pos)) => {
+            collision_shape.shape_type = 1;
+            collision_shape.sphere = GameSphere {
+                diameter: read_f32(buf, pos)?,
+            };
+        }
+        (CYLINDER_SHAPE, Some(pos)) => {
+            collision_shape.shape_type = 2;
+            collision_shape.cylinder = GameCylinder {
+                diameter: read_f32(buf, pos)?,
+                height: read_f32(buf, pos + 4)?,
+            };
+        }
+        _ => return Err(RlibError::InvalidFlatbuffer),
+    }
+
+    Ok(GameBall {
+        physics: match ball.field(ball_info::PHYSICS)? {
+            Some(pos) => read_physics(buf, pos)?,
+            None => GamePhysics::default(),
+        },
+        collision_shape,
+    })
+}
+
+fn read_game_info(match_info: Option<Table>) -> RlibResult<GameInfo> {
+    let Some(match_info) = match_info else {
+        return Ok(GameInfo::default());
+    };
+
+    let match_phase = MatchPhase::from(match_info.u8(match_info::MATCH_PHASE)?);
+
+    Ok(GameInfo {
+        seconds_elapsed: match_info.f32(match_info::SECONDS_ELAPSED)?,
+        game_time_remaining: match_info.f32(match_info::GAME_TIME_REMAINING)?,
+        is_overtime: match_info.bool(match_info::IS_OVERTIME)?,
+        is_round_active: matches!(match_phase, MatchPhase::Kickoff | MatchPhase::Active),
+        is_kickoff_pause: matches!(match_phase, MatchPhase::Countdown | MatchPhase::Kickoff),
+        is_match_ended: match_phase == MatchPhase::Ended,
+        world_gravity_z: match_info.f32(match_info::WORLD_GRAVITY_Z)?,
+    })
+}
+
+impl GamePacket {
+    /// Reads an RLBot v5 `GamePacket` from the bytes of a flatbuffer with it as the root table
+    ///
+    /// Only the first ball is used, and the ball is left at its defaults if there isn't one
+    pub fn from_flatbuffer(bytes: &[u8]) -> RlibResult<Self> {
+        let packet = Table::root(bytes)?;
+
+        let game_cars = packet
+            .tables(game_packet::PLAYERS)?
+            .into_iter()
+            .map(read_car)
+            .collect::<RlibResult<Vec<_>>>()?;
+
+        let game_boosts = packet
+            .structs(game_packet::BOOST_PADS, BOOST_PAD_STATE_SIZE)?
+            .map(|pos| {
+                Ok(GameBoost {
+                    is_active: read::<1>(bytes, pos)?[0] != 0,
+                    timer: read_f32(bytes, pos + 4)?,
+                })
+            })
+            .collect::<RlibResult<Vec<_>>>()?;
+
+        let game_ball = match packet.tables(game_packet::BALLS)?.first() {
+            Some(&ball) => read_ball(ball)?,
+            None => GameBall::default(),
+        };
+
+        Ok(Self {
+            game_info: read_game_info(packet.table(game_packet::MATCH_INFO)?)?,
+            game_ball,
+            num_cars: game_cars.len(),
+            game_cars,
+            num_boost: game_boosts.len(),
+            game_boosts,
+        })
+    }
+}
+
+impl GameFieldInfo {
+    /// Reads an RLBot v5 `FieldInfo` from the bytes of a flatbuffer with it as the root table
+    pub fn from_flatbuffer(bytes: &[u8]) -> RlibResult<Self> {
+        let field_info = Table::root(bytes)?;
+
+        let boost_pads = field_info
+            .structs(field_info::BOOST_PADS, BOOST_PAD_SIZE)?
+            .map(|pos| {
+                Ok(GameBoostPad {
+                    location: read_vec3(bytes, pos)?,
+                    is_full_boost: read::<1>(bytes, pos + VECTOR3_SIZE)?[0] != 0,
+                })
+            })
+            .collect::<RlibResult<Vec<_>>>()?;
+
+        Ok(Self {
+            num_boosts: boost_pads.len(),
+            boost_pads,
+        })
+    }
+}
+
+impl Mutators {
+    /// Reads the mutators out of an RLBot v5 `MatchConfiguration`,
+    /// from the bytes of a flatbuffer with it as the root table
+    pub fn from_flatbuffer(bytes: &[u8]) -> RlibResult<Self> {
+        let Some(mutators) = Table::root(bytes)?.table(match_configuration::MUTATORS)? else {
+            return Ok(Self::new());
+        };
+
+        // v5 added a 5x option before 10x
+        let boost_accel = match mutators.u8(mutator_settings::BOOST_STRENGTH)? {
+            1 => BOOST_ACCEL * 1.5,
+            2 => BOOST_ACCEL * 2.,
+            3 => BOOST_ACCEL * 5.,
+            4 => BOOST_ACCEL * 10.,
+            _ => BOOST_ACCEL,
+        };
+
+        Ok(Self {
+            boost_amount: BoostAmount::from(mutators.u8(mutator_settings::BOOST_AMOUNT)?),
+            boost_accel,
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use flatbuffers::{FlatBufferBuilder, Push, WIPOffset};
+
+    use crate::{
+        constants::BOOST_ACCEL,
+        error::RlibError,
+        pytypes::{GameFieldInfo, GamePacket},
+        world::World,
+        BoostAmount, Mutators,
+    };
+
+    /// Where the field at the index is written in its table's vtable
+    const fn slot(index: usize) -> u16 {
+        4 + 2 * index as u16
+    }
+
+    /// Writes a struct that's made of one type of value into the table
+    fn push_struct<T: Push + Copy>(fbb: &mut FlatBufferBuilder, index: usize, values: &[T]) {
+        for &value in values[1..].iter().rev() {
+            fbb.push(value);
+        }
+        fbb.push_slot_always(slot(index), values[0]);
+    }
+
+    fn physics(location: [f32; 3], yaw: f32, velocity: [f32; 3]) -> [f32; 12] {
+        let [x, y, z] = location;
+        let [vx, vy, vz] = velocity;
+        [x, y, z, 0., yaw, 0., vx, vy, vz, 0., 0., 0.]
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    fn player<'a>(
+        fbb: &mut FlatBufferBuilder<'a>,
+        name: &str,
+        player_id: i32,
+        team: u32,
+        location: [f32; 3],
+        boost: f32,
+        air_state: u8,
+        demolished_timeout: f32,
+        has_dodged: bool,
+    ) -> WIPOffset<flatbuffers::TableFinishedWIPOffset> {
+        let name = fbb.create_string(name);
+
+        let start = fbb.start_table();
+        push_struct(
+            fbb,
+            super::player_info::PHYSICS,
+            &physics(location, FRAC_PI_2, [0., 500., 0.]),
+        );
+        push_struct(fbb, super::player_info::SCORE_INFO, &[3u32, 1, 0, 0, 0, 2, 0]);
+        push_struct(fbb, super::player_info::HITBOX, &[118.01f32, 84.2, 36.16]);
+        push_struct(fbb, super::player_info::HITBOX_OFFSET, &[13.88f32, 0., 20.75]);
+        fbb.push_slot_always(slot(super::player_info::AIR_STATE), air_state);
+        fbb.push_slot_always(slot(super::player_info::DEMOLISHED_TIMEOUT), demolished_timeout);
+        fbb.push_slot_always(slot(super::player_info::IS_BOT), true);
+        fbb.push_slot_always(slot(super::player_info::NAME), name);
+        fbb.push_slot_always(slot(super::player_info::TEAM), team);
+        fbb.push_slot_always(slot(super::player_info::BOOST), boost);
+        fbb.push_slot_always(slot(super::player_info::PLAYER_ID), player_id);
+        fbb.push_slot_always(slot(super::player_info::HAS_JUMPED), has_dodged);
+        fbb.push_slot_always(slot(super::player_info::HAS_DODGED), has_dodged);
+        fbb.end_table(start)
+    }
+
+    /// A v5 `GamePacket` with the ball in the middle, a blue car facing it and a demolished orange car
+    fn game_packet(match_phase: u8) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+
+        let players = [
+            player(&mut fbb, "Virx", 5, 0, [0., -2500., 17.], 33.4, super::ON_GROUND, -1., false),
+            player(&mut fbb, "Bot", 6, 1, [0., 2500., 300.], 100., 3, 2.5, true),
+        ];
+        let players = fbb.create_vector(&players);
+
+        // BoostPadState is a bool, 3 bytes of padding and then a float
+        let boost_pads = [(true, 0f32), (false, 4.5)];
+        fbb.start_vector::<f32>(boost_pads.len() * 2);
+        for &(is_active, timer) in boost_pads.iter().rev() {
+            fbb.push(timer);
+            for _ in 0..3 {
+                fbb.push(0u8);
+            }
+            fbb.push(is_active);
+        }
+        let boost_pads = fbb.end_vector::<f32>(boost_pads.len());
+
+        let sphere = fbb.push(182.5f32);
+        let start = fbb.start_table();
+        push_struct(&mut fbb, super::ball_info::PHYSICS, &physics([0., 0., 92.75], 0., [0.; 3]));
+        fbb.push_slot_always(slot(super::ball_info::SHAPE_TYPE), super::SPHERE_SHAPE);
+        fbb.push_slot_always(slot(super::ball_info::SHAPE), sphere);
+        let ball = fbb.end_table(start);
+        let balls = fbb.create_vector(&[ball]);
+
+        let start = fbb.start_table();
+        fbb.push_slot_always(slot(super::match_info::SECONDS_ELAPSED), 10f32);
+        fbb.push_slot_always(slot(super::match_info::GAME_TIME_REMAINING), 290f32);
+        fbb.push_slot_always(slot(super::match_info::MATCH_PHASE), match_phase);
+        fbb.push_slot_always(slot(super::match_info::WORLD_GRAVITY_Z), -650f32);
+        let match_info = fbb.end_table(start);
+
+        let start = fbb.start_table();
+        fbb.push_slot_always(slot(super::game_packet::PLAYERS), players);
+        fbb.push_slot_always(slot(super::game_packet::BOOST_PADS), boost_pads);
+        fbb.push_slot_always(slot(super::game_packet::BALLS), balls);
+        fbb.push_slot_always(slot(super::game_packet::MATCH_INFO), match_info);
+        let root = fbb.end_table(start);
+
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    pub fn game_packet_round_trip() {
+        let packet = GamePacket::from_flatbuffer(&game_packet(3)).unwrap();
+
+        assert_eq!(packet.game_info.seconds_elapsed, 10.);
+        assert_eq!(packet.game_info.game_time_remaining, 290.);
+        assert_eq!(packet.game_info.world_gravity_z, -650.);
+        assert!(packet.game_info.is_round_active);
+        assert!(!packet.game_info.is_kickoff_pause);
+        assert!(!packet.game_info.is_match_ended);
+
+        assert_eq!(packet.game_ball.physics.location.z, 92.75);
+        assert_eq!(packet.game_ball.collision_shape.shape_type, 1);
+        assert_eq!(packet.game_ball.collision_shape.sphere.diameter, 182.5);
+
+        assert_eq!(packet.num_boost, 2);
+        assert!(packet.game_boosts[0].is_active);
+        assert!(!packet.game_boosts[1].is_active);
+        assert_eq!(packet.game_boosts[1].timer, 4.5);
+
+        assert_eq!(packet.num_cars, 2);
+        let [blue, orange] = &packet.game_cars[..] else {
+            panic!("expected 2 cars");
+        };
+
+        assert_eq!(blue.name, "Virx");
+        assert_eq!(blue.spawn_id, 5);
+        assert_eq!(blue.team, 0);
+        assert_eq!(blue.boost, 33);
+        assert_eq!(blue.physics.location.y, -2500.);
+        assert_eq!(blue.physics.rotation.yaw, FRAC_PI_2);
+        assert_eq!(blue.physics.velocity.y, 500.);
+        assert_eq!(blue.score_info.score, 3);
+        assert_eq!(blue.score_info.shots, 2);
+        assert_eq!(blue.hitbox.length, 118.01);
+        assert_eq!(blue.hitbox.height, 36.16);
+        assert_eq!(blue.hitbox_offset.z, 20.75);
+        assert!(blue.has_wheel_contact);
+        assert!(!blue.is_demolished);
+        assert!(!blue.jumped && !blue.double_jumped);
+
+        assert_eq!(orange.name, "Bot");
+        assert_eq!(orange.spawn_id, 6);
+        assert_eq!(orange.team, 1);
+        assert_eq!(orange.boost, 100);
+        assert!(!orange.has_wheel_contact);
+        assert!(orange.is_demolished);
+        assert!(orange.jumped && orange.double_jumped);
+        assert!(orange.is_bot);
+
+        // the packet is ready to be used as it is
+        let mut world = World::new();
+        world.load_standard();
+        world.tick(packet, None).unwrap();
+        assert!(world.get_num_ball_slices() > 0);
+        assert!(world.get_game_state().is_playing());
+    }
+
+    #[test]
+    pub fn match_phases() {
+        // Inactive, Countdown, Kickoff, Active, GoalScored, Replay, Paused, Ended
+        let expected = [
+            (false, false, false),
+            (false, true, false),
+            (true, true, false),
+            (true, false, false),
+            (false, false, false),
+            (false, false, false),
+            (false, false, false),
+            (false, false, true),
+        ];
+
+        for (match_phase, expected) in expected.into_iter().enumerate() {
+            let game_info = GamePacket::from_flatbuffer(&game_packet(match_phase as u8))
+                .unwrap()
+                .game_info;
+            assert_eq!(
+                (
+                    game_info.is_round_active,
+                    game_info.is_kickoff_pause,
+                    game_info.is_match_ended
+                ),
+                expected,
+                "match phase {match_phase}"
+            );
+        }
+    }
+
+    #[test]
+    pub fn field_info_round_trip() {
+        let mut fbb = FlatBufferBuilder::new();
+
+        // BoostPad is a Vector3, a bool and then 3 bytes of padding
+        let boost_pads = [([0f32, -4240., 70.], false), ([-3072., -4096., 73.], true)];
+        fbb.start_vector::<f32>(boost_pads.len() * 4);
+        for &([x, y, z], is_full_boost) in boost_pads.iter().rev() {
+            for _ in 0..3 {
+                fbb.push(0u8);
+            }
+            fbb.push(is_full_boost);
+            fbb.push(z);
+            fbb.push(y);
+            fbb.push(x);
+        }
+        let boost_pads = fbb.end_vector::<f32>(boost_pads.len());
+
+        let start = fbb.start_table();
+        fbb.push_slot_always(slot(super::field_info::BOOST_PADS), boost_pads);
+        let root = fbb.end_table(start);
+        fbb.finish(root, None);
+
+        let field_info = GameFieldInfo::from_flatbuffer(fbb.finished_data()).unwrap();
+        assert_eq!(field_info.num_boosts, 2);
+        assert_eq!(field_info.boost_pads[0].location.y, -4240.);
+        assert!(!field_info.boost_pads[0].is_full_boost);
+        assert_eq!(field_info.boost_pads[1].location.x, -3072.);
+        assert_eq!(field_info.boost_pads[1].location.z, 73.);
+        assert!(field_info.boost_pads[1].is_full_boost);
+    }
+
+    fn match_configuration(mutators: Option<(u8, u8)>) -> Vec<u8> {
+        let mut fbb = FlatBufferBuilder::new();
+
+        let mutators = mutators.map(|(boost_amount, boost_strength)| {
+            let start = fbb.start_table();
+            fbb.push_slot_always(slot(super::mutator_settings::BOOST_AMOUNT), boost_amount);
+            fbb.push_slot_always(slot(super::mutator_settings::BOOST_STRENGTH), boost_strength);
+            fbb.end_table(start)
+        });
+
+        let start = fbb.start_table();
+        if let Some(mutators) = mutators {
+            fbb.push_slot_always(slot(super::match_configuration::MUTATORS), mutators);
+        }
+        let root = fbb.end_table(start);
+        fbb.finish(root, None);
+        fbb.finished_data().to_vec()
+    }
+
+    #[test]
+    pub fn mutators_round_trip() {
+        let mutators = Mutators::from_flatbuffer(&match_configuration(Some((2, 3)))).unwrap();
+        assert_eq!(mutators.boost_amount, BoostAmount::SlowRecharge);
+        assert_eq!(mutators.boost_accel, BOOST_ACCEL * 5.);
+
+        let mutators = Mutators::from_flatbuffer(&match_configuration(None)).unwrap();
+        assert_eq!(mutators.boost_amount, BoostAmount::Default);
+        assert_eq!(mutators.boost_accel, BOOST_ACCEL);
+    }
+
+    #[test]
+    pub fn invalid_flatbuffers() {
+        assert_eq!(GamePacket::from_flatbuffer(&[]).unwrap_err(), RlibError::InvalidFlatbuffer);
+        assert_eq!(
+            GameFieldInfo::from_flatbuffer(&[255; 3]).unwrap_err(),
+            RlibError::InvalidFlatbuffer
+        );
+        assert_eq!(
+            Mutators::from_flatbuffer(&[200, 0, 0, 0]).unwrap_err(),
+            RlibError::InvalidFlatbuffer
+        );
+
+        // cutting the packet short leaves offsets that point past the end of it
+        let packet = game_packet(3);
+        assert_eq!(
+            GamePacket::from_flatbuffer(&packet[..packet.len() / 2]).unwrap_err(),
+            RlibError::InvalidFlatbuffer
+        );
+    }
+}
@@ -1,13 +1,16 @@
-use std::f32::{consts::E, INFINITY};
+use std::f32::consts::E;
 
 use dubins_paths::{DubinsPath, Intermediate, NoPathError, PathType, PosRot, Result as DubinsResult};
 use glam::Vec3A;
 
 use crate::{
-    car::{throttle_acceleration, Car, FieldRect},
+    car::{curvature, max_turn_speed, throttle_acceleration, Car, FieldRect, Obstacle},
     constants::*,
-    pytypes::{BasicShotInfo, ShotType},
+    dodge::Dodge,
+    pytypes::{AdvancedShotInfo, BasicShotInfo, ControllerState, ShotRejection, ShotType},
+    shot::GroundBasedShot,
     utils::*,
+    wall::Wall,
     BoostAmount, Mutators,
 };
 
@@ -78,17 +81,18 @@ pub fn shortest_path_in_validate(
     q1: PosRot,
     rho: f32,
     car_field: &FieldRect,
+    wall: Option<&Wall>,
     max_distance: f32,
 ) -> DubinsResult<DubinsPath> {
-    let mut best_cost = INFINITY;
+    let mut best_cost = f32::INFINITY;
     let mut best_path = None;
 
     let intermediate_results = Intermediate::from(q0, q1, rho);
 
-    for (i, param) in PathType::ALL
+    // words that can't connect the two points are skipped, so each path type has to stay with its own word
+    for (path_type, param) in PathType::ALL
         .into_iter()
-        .flat_map(|path_type| intermediate_results.word(path_type))
-        .enumerate()
+        .filter_map(|path_type| Some((path_type, intermediate_results.word(path_type).ok()?)))
     {
         let cost = param[0] + param[1] + param[2];
         if cost < best_cost && cost * rho <= max_distance {
@@ -96,10 +100,10 @@ pub fn shortest_path_in_validate(
                 qi: q0,
                 rho,
                 param,
-                type_: PathType::ALL[i],
+                type_: path_type,
             };
 
-            if car_field.is_path_in(&path) {
+            if car_field.is_path_in(&path, wall) {
                 best_cost = cost;
                 best_path = Some(path);
             }
@@ -116,6 +120,41 @@ pub enum CantReachError {
     BackwardsReqTooFast,
 }
 
+impl From<CantReachError> for ShotRejection {
+    #[inline]
+    fn from(err: CantReachError) -> Self {
+        match err {
+            CantReachError::NoTime => ShotRejection::NoTime,
+            CantReachError::ForwardsReqTooFast => ShotRejection::ForwardsReqTooFast,
+            CantReachError::BackwardsReqTooFast => ShotRejection::BackwardsReqTooFast,
+        }
+    }
+}
+
+/// How the car gets through the turns of a path
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq)]
+pub enum Turning {
+    /// Steering with the turn radius of the fastest speed the car can reach
+    #[default]
+    Steer,
+    /// Holding the car to the speed that it changes direction the quickest at while it steers through tighter turns
+    QuickSteer,
+    /// Powersliding through turns that are tighter than the car can steer
+    Powerslide,
+}
+
+impl Turning {
+    /// The fastest that the car can take a turn with a radius of `rho` with
+    #[inline]
+    #[must_use]
+    pub fn max_speed(self, rho: f32) -> f32 {
+        match self {
+            Self::Steer | Self::QuickSteer => max_turn_speed(rho),
+            Self::Powerslide => max_turn_speed(rho * POWERSLIDE_TURN_SCALE),
+        }
+    }
+}
+
 #[derive(Clone, Copy, Debug)]
 pub struct GroundTargetInfo {
     pub distances: [f32; 4],
@@ -126,9 +165,58 @@ pub struct GroundTargetInfo {
     pub shot_vector: Vec3A,
     pub turn_targets: Option<(Vec3A, Vec3A)>,
     pub wait_for_land: bool,
+    /// The wall that the path was unfolded onto, for wall shots
+    pub wall: Option<Wall>,
+    /// When and how to dodge into the ball, for dodge shots
+    pub dodge: Option<Dodge>,
+    /// How the car gets through the turns of the path
+    pub turning: Turning,
 }
 
 impl GroundTargetInfo {
+    /// How far apart the points along the path that are checked for other cars are
+    const OBSTACLE_CHECK_DISTANCE: f32 = 50.;
+
+    /// Checks if the car can drive the path without running into any of the obstacles,
+    /// assuming that it covers the path at the average speed that gets it to the ball in `time_remaining`
+    #[must_use]
+    pub fn is_clear(&self, car: &Car, obstacles: &[Obstacle], time_remaining: f32, gravity: Vec3A) -> bool {
+        if obstacles.is_empty() {
+            return true;
+        }
+
+        let path_length = self.path.length();
+        let total_d = self.distances.iter().sum::<f32>();
+        let endpoint = self.path.endpoint();
+        let (sin, cos) = endpoint.rot.sin_cos();
+        let end_direction = Vec3A::new(cos, sin, 0.);
+        let drive_time = time_remaining - car.time_to_land;
+
+        let mut d = Self::OBSTACLE_CHECK_DISTANCE;
+
+        while d < total_d {
+            let mut location = if d < path_length {
+                self.path.sample(d).pos
+            } else {
+                endpoint.pos + end_direction * (d - path_length)
+            };
+
+            // the height above the unfolded floor is the distance from the wall
+            location.z = car.landing_location.z;
+            location = self.wall.map_or(location, |wall| wall.fold(location));
+
+            let time = car.time_to_land + drive_time * d / total_d;
+
+            if obstacles.iter().any(|obstacle| obstacle.blocks(location, time, gravity)) {
+                return false;
+            }
+
+            d += Self::OBSTACLE_CHECK_DISTANCE;
+        }
+
+        true
+    }
+
     pub fn can_reach(&self, car: &Car, max_time: f32, mutators: Mutators) -> Result<f32, CantReachError> {
         let is_curved = PathType::CCC.contains(&self.path.type_);
         let total_d = self.distances.iter().sum::<f32>();
@@ -197,6 +285,15 @@ impl GroundTargetInfo {
                 return Err(CantReachError::BackwardsReqTooFast);
             }
 
+            let is_turning = !(is_middle_straight || d < self.distances[3]);
+            if is_turning && self.turning == Turning::Powerslide && v.abs() > MIN_POWERSLIDE_SPEED {
+                // the car slides sideways through the turn, so it can't speed up
+                v -= POWERSLIDE_DECEL.copysign(v) * SIMULATION_DT;
+                t_r -= SIMULATION_DT;
+                d -= v * direction * SIMULATION_DT;
+                continue;
+            }
+
             let throttle_accel = throttle_acceleration(v);
             let (mut throttle, mut boost) = get_throttle_and_boost(throttle_accel, b, t.copysign(v), boost_accel);
 
@@ -219,9 +316,10 @@ impl GroundTargetInfo {
                 b -= boost_consumption_dt;
             }
 
-            if !(is_middle_straight || d < self.distances[3]) {
+            if is_turning {
                 accel -= self.path.rho / E * SIMULATION_DT;
-                accel = accel.min(2295. - v);
+                // brake for turns that are too tight to take at the current speed
+                accel = accel.min((self.turning.max_speed(self.path.rho) - v).max(-BRAKE_ACC_DT));
             }
 
             v += accel;
@@ -234,8 +332,10 @@ impl GroundTargetInfo {
 
     #[inline]
     #[must_use]
-    pub const fn get_basic_shot_info(&self, time: f32) -> BasicShotInfo {
-        BasicShotInfo::found(time, self.shot_type, self.shot_vector, self.is_forwards, self.wait_for_land)
+    pub fn get_basic_shot_info(&self, time: f32) -> BasicShotInfo {
+        let shot_vector = self.wall.map_or(self.shot_vector, |wall| wall.fold_vector(self.shot_vector));
+
+        BasicShotInfo::found(time, self.shot_type, shot_vector, self.is_forwards, self.wait_for_land)
     }
 }
 
@@ -264,3 +364,138 @@ fn get_throttle_and_boost(throttle_accel: f32, b: f32, t: f32, boost_accel: f32)
         (0., false)
     }
 }
+
+/// How much sharper than the car's tightest turn at its current speed the path has to be before the car powerslides
+const HANDBRAKE_CURVATURE_RATIO: f32 = 2.;
+
+/// The controls that drive the car along the path of a ground based shot, at the speed that gets it to the ball on time,
+/// and then jump or dodge into the ball when the shot was planned to
+#[must_use]
+pub fn get_controls(
+    car: &Car,
+    shot: &GroundBasedShot,
+    shot_info: &AdvancedShotInfo,
+    time_remaining: f32,
+    mutators: Mutators,
+) -> ControllerState {
+    let direction = if shot.is_forwards { 1. } else { -1. };
+    let v = car.local_velocity.x;
+
+    // the circle that's tangent to the direction that the car is travelling in and goes through the target
+    let local_target = car.localize(Vec3A::from(shot_info.final_target) - car.location);
+    let target_distance = flatten(local_target).length_squared().max(1.);
+    let required_curvature = 2. * local_target.y / target_distance;
+    let max_curvature = curvature(v.abs().min(MAX_SPEED));
+
+    // reversing with the wheels turned right also moves the car towards its right
+    let steer = (required_curvature / max_curvature).clamp(-1., 1.);
+
+    // powerslide through the turns of the path if it was planned to, or if the car has strayed into a turn that's too tight
+    let (segment, _) = shot.find_min_distance_index(flatten(shot.unfold(car.location)));
+    let is_turning = segment < 3 && (segment != 1 || PathType::CCC.contains(&shot.path.type_));
+    let handbrake = v.abs() > MIN_POWERSLIDE_SPEED
+        && ((shot.turning == Turning::Powerslide && is_turning)
+            || required_curvature.abs() > max_curvature * HANDBRAKE_CURVATURE_RATIO);
+
+    let boost_accel = if mutators.boost_amount == BoostAmount::NoBoost {
+        0.
+    } else {
+        mutators.boost_accel
+    };
+
+    let b = if mutators.boost_amount == BoostAmount::Unlimited {
+        100.
+    } else {
+        f32::from(car.boost)
+    };
+
+    // the same speed controller that was used to check if the shot was possible
+    let mut r = shot_info.distance_remaining * direction / time_remaining.max(SIMULATION_DT);
+    if is_turning && shot.is_forwards {
+        r = r.min(shot.turning.max_speed(shot.path.rho));
+    }
+
+    let t = r - v;
+    let (mut throttle, mut boost) = get_throttle_and_boost(throttle_acceleration(v), b, t.copysign(v), boost_accel);
+    throttle *= 1f32.copysign(v);
+    boost &= shot.is_forwards && t > 0.;
+
+    let mut controls = ControllerState {
+        throttle,
+        steer,
+        boost,
+        handbrake,
+        ..Default::default()
+    };
+
+    let Some(jump_time) = shot.jump_time else {
+        return controls;
+    };
+
+    // how long ago the car should have jumped
+    let elapsed = jump_time - time_remaining;
+    if elapsed < 0. {
+        return controls;
+    }
+
+    controls.handbrake = false;
+
+    if let Some(dodge) = shot.dodge {
+        // jump has to be let go of for at least a tick before it can be pressed again to dodge
+        let hold_time = (dodge.time - SIMULATION_DT * 2.).min(MAX_HOLD_TIME);
+        controls.jump = elapsed < hold_time || (dodge.time..dodge.time + SIMULATION_DT * 2.).contains(&elapsed);
+
+        if elapsed >= dodge.time {
+            let (x, y) = shot_info.dodge_direction.unwrap_or((1., 0.));
+            controls.pitch = -x;
+            controls.yaw = y;
+            controls.boost = false;
+        }
+    } else if shot.shot_type == ShotType::DoubleJump {
+        let double_jump_start = MAX_HOLD_TIME + SIMULATION_DT * 2.;
+        controls.jump =
+            elapsed < MAX_HOLD_TIME || (double_jump_start..double_jump_start + SIMULATION_DT * 2.).contains(&elapsed);
+    } else {
+        controls.jump = elapsed < MAX_HOLD_TIME;
+    }
+
+    controls
+}
+
+#[cfg(test)]
+mod tests {
+    use std::{f32::consts::PI, sync::Arc};
+
+    use dubins_paths::PosRot;
+    use glam::Vec3A;
+
+    use crate::{
+        car::{Arena, FieldRect, OCTANE_HITBOX},
+        field::FieldGrid,
+        ground::shortest_path_in_validate,
+    };
+
+    #[test]
+    pub fn paths_end_at_the_target() {
+        let hitbox = OCTANE_HITBOX;
+        let (game, ball) = rl_ball_sym::compressed::load_standard();
+        let field = FieldRect::from(
+            &hitbox,
+            Some(Arc::new(FieldGrid::from_game(&game, ball))),
+            Arena::Soccar.walls(),
+        );
+
+        let q0 = PosRot::new(Vec3A::ZERO, 0.);
+
+        // points close to the car can't be reached with every kind of path when the turns are wide
+        for (x, y) in [(300., 200.), (-200., 400.), (1500., -800.), (-3000., 0.)] {
+            for i in 0..8 {
+                let q1 = PosRot::new(Vec3A::new(x, y, 0.), i as f32 * PI / 4.);
+                let path = shortest_path_in_validate(q0, q1, 500., &field, None, f32::INFINITY).unwrap();
+
+                let end = path.endpoint();
+                assert!(end.pos.distance(q1.pos) < 1., "{q1:?} ended at {end:?}");
+            }
+        }
+    }
+}
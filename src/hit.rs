@@ -0,0 +1,219 @@
+use glam::{Mat3A, Vec3A};
+use rl_ball_sym::simulation::ball::Ball;
+
+use crate::{
+    car::{Arena, Car},
+    constants::*,
+    pytypes::ShotOutcome,
+};
+
+/// Where the car is, and how it's moving, at the moment it touches the ball
+#[derive(Clone, Copy, Debug)]
+pub struct Contact {
+    pub location: Vec3A,
+    pub velocity: Vec3A,
+    pub rotmat: Mat3A,
+}
+
+impl Contact {
+    /// Places the car so the middle of the front of its hitbox touches the ball where the shot vector says to hit it
+    #[must_use]
+    pub fn new(ball: &Ball, shot_vector: Vec3A, rotmat: Mat3A, velocity: Vec3A, car: &Car) -> Self {
+        let contact_point = ball.location - shot_vector * ball.radius();
+        let hitbox_center = contact_point - rotmat.x_axis * car.hitbox.length / 2.;
+
+        Self {
+            location: hitbox_center - rotmat * car.hitbox_offset,
+            velocity,
+            rotmat,
+        }
+    }
+
+    /// The point on the car's hitbox that's closest to `p`
+    fn closest_point(&self, p: Vec3A, car: &Car) -> Vec3A {
+        let hitbox_center = self.location + self.rotmat * car.hitbox_offset;
+        let half_size = Vec3A::new(car.hitbox.length, car.hitbox.width, car.hitbox.height) / 2.;
+        let local = self.rotmat.transpose() * (p - hitbox_center);
+
+        hitbox_center + self.rotmat * local.clamp(-half_size, half_size)
+    }
+
+    /// The velocity of the ball right after the car hits it
+    ///
+    /// This is the physical collision between the car's hitbox and the ball,
+    /// plus the extra impulse that Rocket League adds which mostly ignores the height of the hit
+    #[must_use]
+    pub fn hit(&self, ball: &Ball, car: &Car) -> Vec3A {
+        let forward = self.rotmat.x_axis;
+        let relative_velocity = self.velocity - ball.velocity;
+
+        // the car is much heavier than the ball, so the ball takes most of the speed that the car closes in with
+        let normal = (ball.location - self.closest_point(ball.location, car)).normalize_or_zero();
+        let closing_speed = relative_velocity.dot(normal).max(0.);
+        let mut velocity = ball.velocity + normal * closing_speed * (CAR_MASS / (CAR_MASS + BALL_MASS));
+
+        let mut direction = ball.location - self.location;
+        direction.z *= 0.35;
+        direction = (direction - forward * direction.dot(forward) * 0.35).normalize_or_zero();
+
+        let relative_speed = relative_velocity.length().min(4600.);
+        velocity += direction * relative_speed * extra_impulse_scale(relative_speed);
+
+        velocity.clamp_length_max(BALL_MAX_SPEED)
+    }
+}
+
+/// How much of the extra impulse Rocket League gives the ball on each hit, depending on the relative speed of the car
+fn extra_impulse_scale(relative_speed: f32) -> f32 {
+    if relative_speed <= 500. {
+        0.65
+    } else if relative_speed <= 2300. {
+        0.65 - 0.1 * (relative_speed - 500.) / 1800.
+    } else {
+        0.55 - 0.25 * (relative_speed - 2300.) / 2300.
+    }
+}
+
+/// The rotation of a car facing `forward` with its roof towards `up`
+#[inline]
+#[must_use]
+pub fn rotmat_from(forward: Vec3A, up: Vec3A) -> Mat3A {
+    let left = up.cross(forward).try_normalize().unwrap_or(Vec3A::Y);
+    Mat3A::from_cols(forward, left, forward.cross(left))
+}
+
+/// How much the ball's speed away from the goal has to jump in one tick to count as hitting the goal frame or the back wall
+const IMPACT_SPEED: f32 = 100.;
+/// How far in front of the goal line the ball can hit the goal frame
+const GOAL_FRAME_DEPTH: f32 = 50.;
+
+/// Finds what the ball hits first in a prediction from right after it was hit by a car on `team`,
+/// and when it happens
+///
+/// Returns `None` if the arena doesn't have goals that the ball can be scored in
+#[must_use]
+pub fn predict_outcome(predictions: &[Ball], arena: Arena, team: u8) -> Option<(ShotOutcome, Option<f32>)> {
+    let goal_line = arena.goal_line()?;
+    // blue attacks the orange goal, which is on the positive y side of the field
+    let attacking = if team == 0 { 1. } else { -1. };
+
+    for (before, after) in predictions.iter().zip(predictions.iter().skip(1)) {
+        let radius = after.collision_radius();
+        let y = after.location.y * attacking;
+
+        if y > goal_line + radius {
+            return Some((ShotOutcome::Goal, Some(after.time)));
+        }
+
+        if y < -(goal_line + radius) {
+            return Some((ShotOutcome::OwnGoal, Some(after.time)));
+        }
+
+        if let Some((half_width, height)) = arena.goal_frame() {
+            // a sudden push away from the goal means the ball hit something
+            if y > goal_line - radius - GOAL_FRAME_DEPTH
+                && (after.velocity.y - before.velocity.y) * attacking < -IMPACT_SPEED
+            {
+                let outcome = if after.location.x.abs() < half_width + radius && after.location.z < height + radius {
+                    ShotOutcome::Post
+                } else {
+                    ShotOutcome::Backboard
+                };
+
+                return Some((outcome, Some(after.time)));
+            }
+        }
+    }
+
+    Some((ShotOutcome::Miss, None))
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{
+        car::{grounded_test_car, Arena},
+        hit::{extra_impulse_scale, predict_outcome, rotmat_from, Contact},
+        pytypes::ShotOutcome,
+    };
+    use glam::Vec3A;
+
+    fn outcome(location: Vec3A, velocity: Vec3A, arena: Arena, team: u8) -> Option<(ShotOutcome, Option<f32>)> {
+        let (game, mut ball) = rl_ball_sym::compressed::load_standard();
+        ball.update(0., location, velocity, Vec3A::ZERO);
+        predict_outcome(&ball.get_ball_prediction_struct_for_time(&game, 6.), arena, team)
+    }
+
+    #[test]
+    pub fn impulse_scale() {
+        assert!((extra_impulse_scale(0.) - 0.65).abs() < f32::EPSILON);
+        assert!((extra_impulse_scale(500.) - 0.65).abs() < f32::EPSILON);
+        assert!((extra_impulse_scale(1400.) - 0.6).abs() < 0.0001);
+        assert!((extra_impulse_scale(2300.) - 0.55).abs() < 0.0001);
+        assert!((extra_impulse_scale(4600.) - 0.3).abs() < 0.0001);
+    }
+
+    #[test]
+    pub fn head_on_hit() {
+        let car = grounded_test_car(Vec3A::ZERO, 0., 0);
+
+        let (_, mut ball) = rl_ball_sym::compressed::load_standard();
+        ball.update(0., Vec3A::new(0., 0., 150.), Vec3A::ZERO, Vec3A::ZERO);
+
+        let rotmat = rotmat_from(Vec3A::Y, Vec3A::Z);
+        let contact = Contact::new(&ball, Vec3A::Y, rotmat, Vec3A::Y * 1000., &car);
+
+        // the front of the hitbox touches the ball
+        let front = contact.location + rotmat * car.hitbox_offset + Vec3A::Y * car.hitbox.length / 2.;
+        assert!((front.y - (ball.location.y - ball.radius())).abs() < 0.01);
+
+        // hitting the ball from behind sends it straight ahead, faster than the car but not twice as fast
+        let velocity = contact.hit(&ball, &car);
+        assert!(velocity.x.abs() < 0.01);
+        assert!(velocity.y > 1000. && velocity.y < 2000.);
+
+        // faster cars hit the ball harder
+        let fast_contact = Contact::new(&ball, Vec3A::Y, rotmat, Vec3A::Y * 2000., &car);
+        assert!(fast_contact.hit(&ball, &car).y > velocity.y);
+    }
+
+    #[test]
+    pub fn shot_outcomes() {
+        let towards_orange = Vec3A::new(0., 3000., 0.);
+        let location = Vec3A::new(0., 3000., 93.);
+
+        let (shot_outcome, time) = outcome(location, towards_orange, Arena::Soccar, 0).unwrap();
+        assert_eq!(shot_outcome, ShotOutcome::Goal);
+        assert!(time.unwrap() > 0. && time.unwrap() < 1.);
+
+        // the same ball is an own goal for orange
+        assert_eq!(
+            outcome(location, towards_orange, Arena::Soccar, 1).unwrap().0,
+            ShotOutcome::OwnGoal
+        );
+
+        // a ball that rolls to a stop in the middle of the field doesn't hit anything
+        assert_eq!(
+            outcome(Vec3A::new(0., 0., 93.), Vec3A::new(0., 200., 0.), Arena::Soccar, 0),
+            Some((ShotOutcome::Miss, None))
+        );
+
+        // a ball that flies too wide hits the back wall
+        assert_eq!(
+            outcome(Vec3A::new(2500., 3000., 500.), towards_orange, Arena::Soccar, 0)
+                .unwrap()
+                .0,
+            ShotOutcome::Backboard
+        );
+
+        // a ball that's just too wide hits the post
+        assert_eq!(
+            outcome(Vec3A::new(893., 3000., 300.), towards_orange, Arena::Soccar, 0)
+                .unwrap()
+                .0,
+            ShotOutcome::Post
+        );
+
+        // there are no goals to score in for hoops
+        assert_eq!(outcome(location, towards_orange, Arena::Hoops, 0), None);
+    }
+}
@@ -1,15 +1,17 @@
 use glam::Vec3A;
+#[cfg(feature = "python")]
 use pyo3::{pyclass, pymethods, FromPyObject};
 use rl_ball_sym::simulation::ball::Ball;
 
 use crate::{
     car::Car,
     constants::*,
-    shot::{AirBasedShot, GroundBasedShot},
+    shot::{AirBasedShot, CeilingShot, GroundBasedShot},
     utils::{flatten, get_tuple_from_vec3},
 };
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct Hitbox {
     pub length: f32,
     pub width: f32,
@@ -28,7 +30,8 @@ impl Hitbox {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameVec {
     pub x: f32,
     pub y: f32,
@@ -42,7 +45,8 @@ impl From<GameVec> for Vec3A {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameRot {
     pub pitch: f32,
     pub yaw: f32,
@@ -56,29 +60,33 @@ impl From<GameRot> for Vec3A {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameSphere {
     pub diameter: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameBox {
     pub length: f32,
     pub width: f32,
     pub height: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameCylinder {
     pub diameter: f32,
     pub height: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameCollisionShape {
-    #[pyo3(attribute("type"))]
+    #[cfg_attr(feature = "python", pyo3(attribute("type")))]
     pub shape_type: usize,
-    #[pyo3(attribute("box"))]
+    #[cfg_attr(feature = "python", pyo3(attribute("box")))]
     pub box_: GameBox,
     pub sphere: GameSphere,
     pub cylinder: GameCylinder,
@@ -97,7 +105,8 @@ impl GameCollisionShape {
     }
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GamePhysics {
     pub location: GameVec,
     pub velocity: GameVec,
@@ -105,39 +114,121 @@ pub struct GamePhysics {
     pub rotation: GameRot,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameBall {
     pub physics: GamePhysics,
     pub collision_shape: GameCollisionShape,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameInfo {
     pub seconds_elapsed: f32,
+    pub game_time_remaining: f32,
+    pub is_overtime: bool,
+    pub is_round_active: bool,
+    pub is_kickoff_pause: bool,
+    pub is_match_ended: bool,
     pub world_gravity_z: f32,
 }
 
-#[derive(Clone, Copy, Debug, Default, FromPyObject)]
+impl Default for GameInfo {
+    #[inline]
+    fn default() -> Self {
+        Self {
+            seconds_elapsed: 0.,
+            game_time_remaining: 0.,
+            is_overtime: false,
+            // packets that don't say otherwise are from a round that's being played
+            is_round_active: true,
+            is_kickoff_pause: false,
+            is_match_ended: false,
+            world_gravity_z: 0.,
+        }
+    }
+}
+
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
+pub struct GameScoreInfo {
+    pub score: i32,
+    pub goals: i32,
+    pub own_goals: i32,
+    pub assists: i32,
+    pub saves: i32,
+    pub shots: i32,
+    pub demolitions: i32,
+}
+
+impl GameScoreInfo {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            score: 0,
+            goals: 0,
+            own_goals: 0,
+            assists: 0,
+            saves: 0,
+            shots: 0,
+            demolitions: 0,
+        }
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GameCar {
     pub physics: GamePhysics,
+    pub score_info: GameScoreInfo,
     pub hitbox: Hitbox,
     pub hitbox_offset: GameVec,
+    pub name: String,
+    pub spawn_id: i32,
     pub boost: u8,
+    pub team: u8,
     pub jumped: bool,
     pub double_jumped: bool,
     pub is_demolished: bool,
     pub has_wheel_contact: bool,
+    pub is_super_sonic: bool,
+    pub is_bot: bool,
 }
 
-#[derive(Clone, Debug, Default, FromPyObject)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
+pub struct GameBoost {
+    pub is_active: bool,
+    pub timer: f32,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
 pub struct GamePacket {
     pub game_info: GameInfo,
     pub game_ball: GameBall,
     pub game_cars: Vec<GameCar>,
     pub num_cars: usize,
+    pub game_boosts: Vec<GameBoost>,
+    pub num_boost: usize,
 }
 
-#[pyclass(frozen)]
+#[derive(Clone, Copy, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
+pub struct GameBoostPad {
+    pub location: GameVec,
+    pub is_full_boost: bool,
+}
+
+#[derive(Clone, Debug, Default)]
+#[cfg_attr(feature = "python", derive(FromPyObject))]
+pub struct GameFieldInfo {
+    pub boost_pads: Vec<GameBoostPad>,
+    pub num_boosts: usize,
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen))]
 #[derive(Clone, Copy, Debug, PartialEq, Eq, Default)]
 pub enum ShotType {
     #[default]
@@ -145,6 +236,12 @@ pub enum ShotType {
     Jump,
     DoubleJump,
     Aerial,
+    /// Driving up a side or back wall, jumping off of it if `required_jump_time` is set
+    Wall,
+    /// Driving up the wall onto the ceiling, then dropping off of it into an aerial with the flip still available
+    Ceiling,
+    /// Jumping, then dodging forwards into the ball for extra power
+    Dodge,
 }
 
 impl ShotType {
@@ -156,11 +253,113 @@ impl ShotType {
             ShotType::Jump => "Jump",
             ShotType::DoubleJump => "DoubleJump",
             ShotType::Aerial => "Aerial",
+            ShotType::Wall => "Wall",
+            ShotType::Ceiling => "Ceiling",
+            ShotType::Dodge => "Dodge",
+        }
+    }
+}
+
+/// What the ball hits first after the car touches it
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShotOutcome {
+    /// The ball goes into the opponent's goal
+    Goal,
+    /// The ball goes into the car's own goal
+    OwnGoal,
+    /// The ball hits the posts or the crossbar of the opponent's goal
+    Post,
+    /// The ball bounces off of the back wall around the opponent's goal
+    Backboard,
+    /// None of the above happen before the end of the prediction
+    Miss,
+}
+
+impl ShotOutcome {
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            ShotOutcome::Goal => "Goal",
+            ShotOutcome::OwnGoal => "OwnGoal",
+            ShotOutcome::Post => "Post",
+            ShotOutcome::Backboard => "Backboard",
+            ShotOutcome::Miss => "Miss",
+        }
+    }
+}
+
+/// Why a ball slice was rejected during the shot search
+#[cfg_attr(feature = "python", pyclass(frozen))]
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ShotRejection {
+    /// The ball is past the goal line
+    BallOutOfField,
+    /// The shot type required to hit the ball hasn't been enabled, or the car can't jump yet
+    ShotTypeUnavailable,
+    /// The ball won't fit between the target's posts
+    PostDoesntFit,
+    /// The path is longer than the furthest the car can travel in time
+    TooFar,
+    /// The car is too close to the ball to line up the shot
+    TooClose,
+    /// There isn't enough time to complete the required jump
+    NotEnoughJumpTime,
+    /// The point where the car finishes turning is outside of the field
+    ExitOutOfField,
+    /// None of the Dubin's paths stay inside of the field
+    NoPathInField,
+    /// The car ran out of time while driving the path
+    NoTime,
+    /// Driving forwards, the car can't reach the required speed
+    ForwardsReqTooFast,
+    /// Driving backwards, the car can't reach the required speed
+    BackwardsReqTooFast,
+    /// The car is still waiting to be able to jump
+    AerialNotReady,
+    /// The car can't get into a position to hit the ball towards the target
+    AerialBadApproach,
+    /// The car can't turn to face the target in time
+    AerialTurnTooSlow,
+    /// The required acceleration is too close to the car's maximum
+    AerialTooMuchAcceleration,
+    /// The car doesn't have enough boost
+    AerialBoostShortfall,
+    /// The car would be going too fast when it reaches the target
+    AerialTooFast,
+    /// Another car will be in the way of the path
+    PathBlocked,
+}
+
+impl ShotRejection {
+    #[inline]
+    #[must_use]
+    pub const fn to_str(self) -> &'static str {
+        match self {
+            ShotRejection::BallOutOfField => "BallOutOfField",
+            ShotRejection::ShotTypeUnavailable => "ShotTypeUnavailable",
+            ShotRejection::PostDoesntFit => "PostDoesntFit",
+            ShotRejection::TooFar => "TooFar",
+            ShotRejection::TooClose => "TooClose",
+            ShotRejection::NotEnoughJumpTime => "NotEnoughJumpTime",
+            ShotRejection::ExitOutOfField => "ExitOutOfField",
+            ShotRejection::NoPathInField => "NoPathInField",
+            ShotRejection::NoTime => "NoTime",
+            ShotRejection::ForwardsReqTooFast => "ForwardsReqTooFast",
+            ShotRejection::BackwardsReqTooFast => "BackwardsReqTooFast",
+            ShotRejection::AerialNotReady => "AerialNotReady",
+            ShotRejection::AerialBadApproach => "AerialBadApproach",
+            ShotRejection::AerialTurnTooSlow => "AerialTurnTooSlow",
+            ShotRejection::AerialTooMuchAcceleration => "AerialTooMuchAcceleration",
+            ShotRejection::AerialBoostShortfall => "AerialBoostShortfall",
+            ShotRejection::AerialTooFast => "AerialTooFast",
+            ShotRejection::PathBlocked => "PathBlocked",
         }
     }
 }
 
-#[pyclass(frozen, get_all)]
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
 #[derive(Clone, Copy, Debug, Default)]
 pub struct TargetOptions {
     pub min_slice: Option<usize>,
@@ -168,11 +367,24 @@ pub struct TargetOptions {
     pub use_absolute_max_values: Option<bool>,
     pub all: Option<bool>,
     pub forwards_only: Option<bool>,
+    pub simulate_aerials: Option<bool>,
+    pub avoid_teammates: Option<bool>,
+    pub avoid_opponents: Option<bool>,
+    /// Search for shots that dodge into the ball, which are tried before jump shots
+    pub dodge_shots: Option<bool>,
+    /// Return the highest ranked shot instead of the earliest one
+    pub rank: Option<bool>,
+    /// Keep searching for shots during kickoff countdowns, goal replays, and after the match ends
+    pub search_while_stopped: Option<bool>,
+    /// Search for shots that drive up a wall and drop off of the ceiling into an aerial
+    pub ceiling_shots: Option<bool>,
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl TargetOptions {
     #[new]
+    #[allow(clippy::too_many_arguments)]
     #[inline]
     const fn __new__(
         min_slice: Option<usize>,
@@ -180,6 +392,13 @@ impl TargetOptions {
         use_absolute_max_values: Option<bool>,
         all: Option<bool>,
         forwards_only: Option<bool>,
+        simulate_aerials: Option<bool>,
+        avoid_teammates: Option<bool>,
+        avoid_opponents: Option<bool>,
+        dodge_shots: Option<bool>,
+        rank: Option<bool>,
+        search_while_stopped: Option<bool>,
+        ceiling_shots: Option<bool>,
     ) -> Self {
         Self {
             min_slice,
@@ -187,11 +406,18 @@ impl TargetOptions {
             use_absolute_max_values,
             all,
             forwards_only,
+            simulate_aerials,
+            avoid_teammates,
+            avoid_opponents,
+            dodge_shots,
+            rank,
+            search_while_stopped,
+            ceiling_shots,
         }
     }
 
     fn __str__(&self) -> String {
-        let mut s = Vec::with_capacity(4);
+        let mut s = Vec::with_capacity(12);
 
         if let Some(min_slice) = self.min_slice {
             s.push(format!("min_slice=={min_slice}"));
@@ -213,26 +439,66 @@ impl TargetOptions {
             s.push(format!("forwards_only=={forwards_only}"));
         }
 
+        if let Some(simulate_aerials) = self.simulate_aerials {
+            s.push(format!("simulate_aerials=={simulate_aerials}"));
+        }
+
+        if let Some(avoid_teammates) = self.avoid_teammates {
+            s.push(format!("avoid_teammates=={avoid_teammates}"));
+        }
+
+        if let Some(avoid_opponents) = self.avoid_opponents {
+            s.push(format!("avoid_opponents=={avoid_opponents}"));
+        }
+
+        if let Some(dodge_shots) = self.dodge_shots {
+            s.push(format!("dodge_shots=={dodge_shots}"));
+        }
+
+        if let Some(rank) = self.rank {
+            s.push(format!("rank=={rank}"));
+        }
+
+        if let Some(search_while_stopped) = self.search_while_stopped {
+            s.push(format!("search_while_stopped=={search_while_stopped}"));
+        }
+
+        if let Some(ceiling_shots) = self.ceiling_shots {
+            s.push(format!("ceiling_shots=={ceiling_shots}"));
+        }
+
         s.join(", ")
     }
 
     #[inline]
     fn __repr__(&self) -> String {
         format!(
-            "TargetOptions(min_slice={:?}, max_slice={:?}, use_absolute_max_values={:?}, all={:?}, forwards_only={:?})",
-            self.min_slice, self.max_slice, self.use_absolute_max_values, self.all, self.forwards_only
+            "TargetOptions(min_slice={:?}, max_slice={:?}, use_absolute_max_values={:?}, all={:?}, forwards_only={:?}, simulate_aerials={:?}, avoid_teammates={:?}, avoid_opponents={:?}, dodge_shots={:?}, rank={:?}, search_while_stopped={:?}, ceiling_shots={:?})",
+            self.min_slice,
+            self.max_slice,
+            self.use_absolute_max_values,
+            self.all,
+            self.forwards_only,
+            self.simulate_aerials,
+            self.avoid_teammates,
+            self.avoid_opponents,
+            self.dodge_shots,
+            self.rank,
+            self.search_while_stopped,
+            self.ceiling_shots
         )
     }
 }
 
-#[pyclass(frozen, get_all)]
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
 pub struct BasicShotInfo {
-    found: bool,
-    time: f32,
-    shot_type: Option<ShotType>,
-    shot_vector: (f32, f32, f32),
-    is_forwards: bool,
-    wait_for_land: bool,
+    pub found: bool,
+    pub time: f32,
+    pub shot_type: Option<ShotType>,
+    pub shot_vector: (f32, f32, f32),
+    pub is_forwards: bool,
+    pub wait_for_land: bool,
 }
 
 impl Default for BasicShotInfo {
@@ -270,6 +536,7 @@ impl BasicShotInfo {
     }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl BasicShotInfo {
     #[inline]
@@ -281,13 +548,12 @@ impl BasicShotInfo {
     }
 }
 
-#[pyclass(frozen, get_all)]
-#[allow(dead_code)]
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
 pub struct BallSlice {
-    time: f32,
-    location: (f32, f32, f32),
-    velocity: (f32, f32, f32),
-    angular_velocity: (f32, f32, f32),
+    pub time: f32,
+    pub location: (f32, f32, f32),
+    pub velocity: (f32, f32, f32),
+    pub angular_velocity: (f32, f32, f32),
 }
 
 impl From<Ball> for BallSlice {
@@ -302,6 +568,7 @@ impl From<Ball> for BallSlice {
     }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl BallSlice {
     #[inline]
@@ -313,18 +580,307 @@ impl BallSlice {
     }
 }
 
-type PyVec3A = (f32, f32, f32);
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
+pub struct SliceDiagnostic {
+    pub slice_num: usize,
+    pub time: f32,
+    pub shot_type: Option<ShotType>,
+    pub rejection: Option<ShotRejection>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl SliceDiagnostic {
+    #[inline]
+    fn __str__(&self) -> String {
+        let shot_type = self.shot_type.map_or("Unknown", ShotType::to_str);
+
+        match self.rejection {
+            Some(rejection) => format!("{shot_type} @{:.2}s - rejected: {}", self.time, rejection.to_str()),
+            None => format!("{shot_type} @{:.2}s - viable", self.time),
+        }
+    }
+}
+
+pub type PyVec3A = (f32, f32, f32);
+
+/// A viable shot, and the metrics that it was ranked by
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
+pub struct RankedShot {
+    pub shot: BasicShotInfo,
+    pub slice_num: usize,
+    pub time_to_contact: f32,
+    /// How much faster the car could be going than the average speed that the shot requires
+    pub speed_margin: f32,
+    /// An estimate of how much boost the shot will use
+    pub boost_cost: f32,
+    /// How closely the ball will be hit towards the middle of the target, from -1 to 1, if the target has posts
+    pub alignment: Option<f32>,
+    /// An estimate of how fast the ball will be going towards the opponent's goal right after the hit
+    pub ball_speed_toward_goal: f32,
+    /// Higher is better
+    pub score: f32,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl RankedShot {
+    #[inline]
+    fn __str__(&self) -> String {
+        let shot_type = self.shot.shot_type.map_or("Unknown", ShotType::to_str);
+
+        format!(
+            "{shot_type} @{:.2}s - score: {:.2}, speed margin: {:.0}, boost cost: {:.0}, ball speed toward goal: {:.0}",
+            self.shot.time, self.score, self.speed_margin, self.boost_cost, self.ball_speed_toward_goal
+        )
+    }
+}
+
+/// The earliest that a car can get to the ball
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
+pub struct CarIntercept {
+    pub car_index: usize,
+    pub team: u8,
+    /// The earliest ball slice that the car can hit, or None if it can't hit any of them
+    pub slice_num: Option<usize>,
+    /// The game time of the slice
+    pub time: Option<f32>,
+    pub shot_type: Option<ShotType>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl CarIntercept {
+    #[inline]
+    fn __str__(&self) -> String {
+        match (self.time, self.shot_type) {
+            (Some(time), Some(shot_type)) => format!(
+                "Car {} (team {}) - {} @{time:.2}s",
+                self.car_index,
+                self.team,
+                shot_type.to_str()
+            ),
+            _ => format!("Car {} (team {}) - no intercept", self.car_index, self.team),
+        }
+    }
+}
 
-#[pyclass(frozen, get_all)]
-#[allow(dead_code)]
+/// What part of the match the game is in
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
+pub struct GameState {
+    /// The seconds left on the game clock
+    pub game_time_remaining: f32,
+    pub is_overtime: bool,
+    /// False during kickoff countdowns, goal replays, and after the match ends
+    pub is_round_active: bool,
+    /// True from the start of the kickoff countdown until the ball is first touched
+    pub is_kickoff_pause: bool,
+    pub is_match_ended: bool,
+}
+
+impl GameState {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            game_time_remaining: 0.,
+            is_overtime: false,
+            is_round_active: true,
+            is_kickoff_pause: false,
+            is_match_ended: false,
+        }
+    }
+
+    /// If the cars can move and the ball can be hit
+    #[inline]
+    #[must_use]
+    pub const fn is_playing(&self) -> bool {
+        self.is_round_active && !self.is_match_ended
+    }
+}
+
+impl Default for GameState {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl From<GameInfo> for GameState {
+    #[inline]
+    fn from(game_info: GameInfo) -> Self {
+        Self {
+            game_time_remaining: game_info.game_time_remaining,
+            is_overtime: game_info.is_overtime,
+            is_round_active: game_info.is_round_active,
+            is_kickoff_pause: game_info.is_kickoff_pause,
+            is_match_ended: game_info.is_match_ended,
+        }
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl GameState {
+    #[inline]
+    fn __str__(&self) -> String {
+        let state = if self.is_match_ended {
+            "Match ended"
+        } else if !self.is_round_active {
+            "Paused"
+        } else if self.is_kickoff_pause {
+            "Kickoff"
+        } else {
+            "Playing"
+        };
+
+        if self.is_overtime {
+            format!("{state} (overtime)")
+        } else {
+            format!("{state} ({:.0}s left)", self.game_time_remaining)
+        }
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!(
+            "GameState(game_time_remaining={}, is_overtime={}, is_round_active={}, is_kickoff_pause={}, is_match_ended={})",
+            self.game_time_remaining, self.is_overtime, self.is_round_active, self.is_kickoff_pause, self.is_match_ended
+        )
+    }
+}
+
+/// The ball right after the car hits it, and what it does next
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug)]
+pub struct PostHitPrediction {
+    pub time: f32,
+    pub location: PyVec3A,
+    pub velocity: PyVec3A,
+    /// What the ball hits first, or None if the arena doesn't have goals
+    pub outcome: Option<ShotOutcome>,
+    /// The game time of the outcome, if something was hit
+    pub outcome_time: Option<f32>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl PostHitPrediction {
+    #[inline]
+    fn __str__(&self) -> String {
+        let outcome = self.outcome.map_or("Unknown", ShotOutcome::to_str);
+
+        match self.outcome_time {
+            Some(outcome_time) => format!(
+                "Hit @{:.2}s - velocity: {:?}, {outcome} @{outcome_time:.2}s",
+                self.time, self.velocity
+            ),
+            None => format!("Hit @{:.2}s - velocity: {:?}, {outcome}", self.time, self.velocity),
+        }
+    }
+}
+
+/// The inputs for a single tick, in the same layout as RLBot's `SimpleControllerState`
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct ControllerState {
+    pub throttle: f32,
+    pub steer: f32,
+    pub pitch: f32,
+    pub yaw: f32,
+    pub roll: f32,
+    pub jump: bool,
+    pub boost: bool,
+    pub handbrake: bool,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl ControllerState {
+    #[inline]
+    fn __str__(&self) -> String {
+        format!(
+            "throttle: {:.2}, steer: {:.2}, pitch: {:.2}, yaw: {:.2}, roll: {:.2}, jump: {}, boost: {}, handbrake: {}",
+            self.throttle, self.steer, self.pitch, self.yaw, self.roll, self.jump, self.boost, self.handbrake
+        )
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Copy, Debug, Default)]
+pub struct BoostPadOptions {
+    /// Only consider pads on the car's own half of the field
+    pub own_half: Option<bool>,
+    /// Only consider pads that lie roughly between the car and this point
+    pub between: Option<PyVec3A>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl BoostPadOptions {
+    #[new]
+    #[inline]
+    const fn __new__(own_half: Option<bool>, between: Option<PyVec3A>) -> Self {
+        Self { own_half, between }
+    }
+
+    #[inline]
+    fn __repr__(&self) -> String {
+        format!("BoostPadOptions(own_half={:?}, between={:?})", self.own_half, self.between)
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
+#[derive(Clone, Debug)]
+pub struct BoostPadRoute {
+    /// The index of the pad in the field info
+    pub pad_index: usize,
+    pub location: PyVec3A,
+    pub is_full_boost: bool,
+    /// The number of seconds from now until the car can pick up the pad
+    pub time: f32,
+    pub distance: f32,
+    pub path_samples: Vec<(f32, f32)>,
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl BoostPadRoute {
+    #[inline]
+    fn __str__(&self) -> String {
+        format!(
+            "{} pad {} @{:.2}s - distance: {:.0}",
+            if self.is_full_boost { "Full" } else { "Small" },
+            self.pad_index,
+            self.time,
+            self.distance
+        )
+    }
+}
+
+#[cfg_attr(feature = "python", pyclass(frozen, get_all))]
 pub struct AdvancedShotInfo {
-    final_target: PyVec3A,
-    distance_remaining: f32,
-    required_jump_time: Option<f32>,
-    path_samples: Vec<(f32, f32)>,
-    current_path_point: PyVec3A,
-    turn_targets: Option<(PyVec3A, PyVec3A)>,
-    num_jumps: Option<u8>,
+    pub final_target: PyVec3A,
+    pub distance_remaining: f32,
+    pub required_jump_time: Option<f32>,
+    pub path_samples: Vec<PyVec3A>,
+    pub current_path_point: PyVec3A,
+    pub turn_targets: Option<(PyVec3A, PyVec3A)>,
+    pub num_jumps: Option<u8>,
+    /// Where the car should drop off of the ceiling, for ceiling shots
+    pub leave_ceiling_location: Option<PyVec3A>,
+    /// The game time when the car should drop off of the ceiling, for ceiling shots
+    pub leave_ceiling_time: Option<f32>,
+    /// The time after the start of the jump to dodge, for dodge shots
+    pub dodge_time: Option<f32>,
+    /// The direction to dodge in relative to the car, where +x is forwards and +y is right, for dodge shots
+    pub dodge_direction: Option<(f32, f32)>,
+    /// The estimated velocity of the ball right after the car dodges into it, for dodge shots
+    pub post_dodge_ball_velocity: Option<PyVec3A>,
 }
 
 impl AdvancedShotInfo {
@@ -341,6 +897,7 @@ impl AdvancedShotInfo {
     }
 }
 
+#[cfg(feature = "python")]
 #[pymethods]
 impl AdvancedShotInfo {
     #[inline]
@@ -361,11 +918,14 @@ impl AdvancedShotInfo {
 
 impl AdvancedShotInfo {
     pub fn get_from_ground(car: &Car, shot: &GroundBasedShot) -> Option<Self> {
-        let (segment, pre_index) = shot.find_min_distance_index(car.location);
+        // for wall shots, the path is in the unfolded floor plane
+        let car_location = flatten(shot.unfold(car.location));
+
+        let (segment, pre_index) = shot.find_min_distance_index(car_location);
         let (distance_along, index) = shot.get_distance_along_shot_and_index(segment, pre_index);
         let current_path_point = shot.samples[segment][pre_index];
 
-        if current_path_point.distance(flatten(car.location)) > car.hitbox.length / 2. {
+        if current_path_point.distance(car_location) > car.hitbox.length / 2. {
             return None;
         }
 
@@ -393,17 +953,26 @@ impl AdvancedShotInfo {
             .collect();
 
         Some(Self {
-            final_target: get_tuple_from_vec3(flatten(target)),
+            final_target: get_tuple_from_vec3(shot.fold(flatten(target))),
             distance_remaining: distance_to_ball,
             path_samples: samples,
             required_jump_time: shot.jump_time,
-            current_path_point: get_tuple_from_vec3(flatten(current_path_point)),
+            current_path_point: get_tuple_from_vec3(shot.fold(flatten(current_path_point))),
             turn_targets: if let Some((a, b)) = shot.turn_targets {
                 Some((get_tuple_from_vec3(flatten(a)), get_tuple_from_vec3(flatten(b))))
             } else {
                 None
             },
             num_jumps: None,
+            leave_ceiling_location: None,
+            leave_ceiling_time: None,
+            dodge_time: shot.dodge.map(|dodge| dodge.time),
+            dodge_direction: shot.dodge.map(|dodge| {
+                let local = car.localize(dodge.direction);
+                let direction = flatten(local).normalize_or_zero();
+                (direction.x, direction.y)
+            }),
+            post_dodge_ball_velocity: shot.dodge.map(|dodge| get_tuple_from_vec3(dodge.ball_velocity)),
         })
     }
 
@@ -418,6 +987,33 @@ impl AdvancedShotInfo {
             current_path_point: get_tuple_from_vec3(car.location),
             turn_targets: None,
             num_jumps: Some(shot.jump_type as u8),
+            leave_ceiling_location: None,
+            leave_ceiling_time: None,
+            dodge_time: None,
+            dodge_direction: None,
+            post_dodge_ball_velocity: None,
+        }
+    }
+
+    /// While driving up the wall and across the ceiling
+    #[must_use]
+    pub fn get_from_ceiling_path(car: &Car, shot: &CeilingShot) -> Option<Self> {
+        Some(Self {
+            num_jumps: Some(shot.air.jump_type as u8),
+            leave_ceiling_location: Some(get_tuple_from_vec3(shot.leave_location)),
+            leave_ceiling_time: Some(shot.leave_time),
+            ..Self::get_from_ground(car, &shot.ground)?
+        })
+    }
+
+    /// After dropping off of the ceiling
+    #[inline]
+    #[must_use]
+    pub fn get_from_ceiling_drop(car: &Car, shot: &CeilingShot) -> Self {
+        Self {
+            leave_ceiling_location: Some(get_tuple_from_vec3(shot.leave_location)),
+            leave_ceiling_time: Some(shot.leave_time),
+            ..Self::get_from_air(car, &shot.air)
         }
     }
 }
@@ -3,22 +3,20 @@ use glam::Vec3A;
 use rl_ball_sym::simulation::ball::Ball;
 
 use crate::{
-    air::{AerialJumpType, AerialTargetInfo},
-    ground::GroundTargetInfo,
-    pytypes::{ShotType, TargetOptions},
-    utils::get_samples_from_line,
+    air::{AerialJumpType, AerialTargetInfo, CeilingTargetInfo},
+    dodge::Dodge,
+    error::{RlibError, RlibResult},
+    ground::{GroundTargetInfo, Turning},
+    pytypes::{PyVec3A, ShotType, TargetOptions},
+    utils::{get_samples_from_line, get_tuple_from_vec3},
+    wall::Wall,
 };
 
-#[inline]
-const fn posrot_to_xy_tuple(posrot: &PosRot) -> (f32, f32) {
-    let [x, y, _] = posrot.pos.to_array();
-    (x, y)
-}
-
 #[derive(Clone, Debug)]
 pub enum Shot {
     GroundBased(Box<GroundBasedShot>),
     AirBased(AirBasedShot),
+    Ceiling(Box<CeilingShot>),
 }
 
 impl Shot {
@@ -28,6 +26,7 @@ impl Shot {
         match self {
             Shot::GroundBased(shot) => shot.time,
             Shot::AirBased(shot) => shot.time,
+            Shot::Ceiling(shot) => shot.air.time,
         }
     }
 
@@ -37,6 +36,7 @@ impl Shot {
         match self {
             Shot::GroundBased(shot) => shot.ball_location,
             Shot::AirBased(shot) => shot.ball_location,
+            Shot::Ceiling(shot) => shot.air.ball_location,
         }
     }
 }
@@ -55,12 +55,48 @@ impl From<AirBasedShot> for Shot {
     }
 }
 
+impl From<CeilingShot> for Shot {
+    #[inline]
+    fn from(shot: CeilingShot) -> Self {
+        Shot::Ceiling(Box::new(shot))
+    }
+}
+
+#[derive(Clone, Debug, Default)]
+pub struct CeilingShot {
+    /// The path up the wall and onto the ceiling
+    pub ground: GroundBasedShot,
+    /// The aerial after dropping off of the ceiling
+    pub air: AirBasedShot,
+    pub leave_location: Vec3A,
+    /// The game time when the car should drop off of the ceiling
+    pub leave_time: f32,
+}
+
+impl CeilingShot {
+    #[inline]
+    #[must_use]
+    pub fn new(ball: &Ball, target_info: &CeilingTargetInfo, game_time: f32) -> Self {
+        Self {
+            ground: GroundBasedShot::new_at(
+                game_time + target_info.leave_time,
+                target_info.leave_location,
+                &target_info.ground,
+            ),
+            air: AirBasedShot::new(ball, &target_info.aerial),
+            leave_location: target_info.leave_location,
+            leave_time: game_time + target_info.leave_time,
+        }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct AirBasedShot {
     pub time: f32,
     pub final_target: Vec3A,
     pub jump_type: AerialJumpType,
     pub ball_location: Vec3A,
+    pub shot_vector: Vec3A,
 }
 
 impl AirBasedShot {
@@ -72,6 +108,7 @@ impl AirBasedShot {
             final_target: target_info.final_target,
             jump_type: target_info.jump_type,
             ball_location: ball.location,
+            shot_vector: target_info.shot_vector,
         }
     }
 }
@@ -82,20 +119,32 @@ pub struct GroundBasedShot {
     pub ball_location: Vec3A,
     pub direction: Vec3A,
     pub distances: [f32; 4],
-    pub all_samples: Vec<(f32, f32)>,
+    /// Samples of the whole path, folded back onto the wall for wall shots
+    pub all_samples: Vec<PyVec3A>,
+    /// Samples of each section of the path, in the floor plane
     pub samples: [Vec<Vec3A>; 4],
     pub path: DubinsPath,
     pub path_endpoint: PosRot,
     pub shot_type: ShotType,
     pub jump_time: Option<f32>,
+    pub is_forwards: bool,
     pub turn_targets: Option<(Vec3A, Vec3A)>,
+    pub wall: Option<Wall>,
+    pub dodge: Option<Dodge>,
+    pub turning: Turning,
 }
 
 impl GroundBasedShot {
     const STEP_DISTANCE: f32 = 10.;
     pub const ALL_STEP: usize = 3;
 
+    #[inline]
     pub fn new(ball: &Ball, target: &GroundTargetInfo) -> Self {
+        Self::new_at(ball.time, ball.location, target)
+    }
+
+    /// For targets that aren't the ball, such as a destination
+    pub fn new_at(time: f32, location: Vec3A, target: &GroundTargetInfo) -> Self {
         let direction = target.shot_vector;
         let path_endpoint = target.path.endpoint();
 
@@ -119,13 +168,15 @@ impl GroundBasedShot {
                 get_samples_from_line(path_endpoint, direction, target.distances[3], Self::STEP_DISTANCE),
             ];
 
+            let fold = |posrot: &PosRot| get_tuple_from_vec3(target.wall.map_or(posrot.pos, |wall| wall.fold(posrot.pos)));
+
             (
                 raw_samples[0]
                     .iter()
-                    .map(posrot_to_xy_tuple)
-                    .chain(raw_samples[1].iter().map(posrot_to_xy_tuple))
-                    .chain(raw_samples[2].iter().map(posrot_to_xy_tuple))
-                    .chain(raw_samples[3].iter().map(posrot_to_xy_tuple))
+                    .map(fold)
+                    .chain(raw_samples[1].iter().map(fold))
+                    .chain(raw_samples[2].iter().map(fold))
+                    .chain(raw_samples[3].iter().map(fold))
                     .step_by(Self::ALL_STEP)
                     .collect(),
                 [
@@ -138,8 +189,8 @@ impl GroundBasedShot {
         };
 
         Self {
-            time: ball.time,
-            ball_location: ball.location,
+            time,
+            ball_location: location,
             direction,
             distances: target.distances,
             all_samples,
@@ -148,10 +199,28 @@ impl GroundBasedShot {
             path_endpoint,
             shot_type: target.shot_type,
             jump_time: target.jump_time,
+            is_forwards: target.is_forwards,
             turn_targets: target.turn_targets,
+            wall: target.wall,
+            dodge: target.dodge,
+            turning: target.turning,
         }
     }
 
+    /// Moves a point on the wall into the same plane as the path
+    #[inline]
+    #[must_use]
+    pub fn unfold(&self, p: Vec3A) -> Vec3A {
+        self.wall.map_or(p, |wall| wall.unfold(p))
+    }
+
+    /// Moves a point in the plane of the path back onto the wall
+    #[inline]
+    #[must_use]
+    pub fn fold(&self, p: Vec3A) -> Vec3A {
+        self.wall.map_or(p, |wall| wall.fold(p))
+    }
+
     fn find_min_distance_in_segment_index(&self, segment: usize, target: Vec3A) -> (usize, f32) {
         let mut min_distance = f32::MAX;
         let mut start_index = 0;
@@ -225,6 +294,13 @@ pub struct Options {
     pub min_slice: usize,
     pub max_slice: usize,
     pub forwards_only: bool,
+    pub simulate_aerials: bool,
+    pub avoid_teammates: bool,
+    pub avoid_opponents: bool,
+    pub dodge_shots: bool,
+    pub rank: bool,
+    pub search_while_stopped: bool,
+    pub ceiling_shots: bool,
 }
 
 impl Options {
@@ -237,6 +313,13 @@ impl Options {
                 min_slice: options.min_slice.unwrap_or(0),
                 max_slice: options.max_slice.unwrap_or(max_slices),
                 forwards_only: options.forwards_only.unwrap_or(false),
+                simulate_aerials: options.simulate_aerials.unwrap_or(false),
+                avoid_teammates: options.avoid_teammates.unwrap_or(false),
+                avoid_opponents: options.avoid_opponents.unwrap_or(false),
+                dodge_shots: options.dodge_shots.unwrap_or(false),
+                rank: options.rank.unwrap_or(false),
+                search_while_stopped: options.search_while_stopped.unwrap_or(false),
+                ceiling_shots: options.ceiling_shots.unwrap_or(false),
             },
             None => Self {
                 max_slice: max_slices,
@@ -260,10 +343,28 @@ impl TargetLocation {
     }
 }
 
+/// A point on the field for the car to drive to
+#[derive(Clone, Copy, Debug, Default)]
+pub struct Destination {
+    pub location: Vec3A,
+    /// The direction the car should be facing when it arrives, or None for whichever is fastest
+    pub arrival_yaw: Option<f32>,
+}
+
+impl Destination {
+    #[inline]
+    #[must_use]
+    pub const fn new(location: Vec3A, arrival_yaw: Option<f32>) -> Self {
+        Self { location, arrival_yaw }
+    }
+}
+
 #[derive(Clone, Debug, Default)]
 pub struct Target {
-    pub car_index: usize,
+    /// The index of the car in the game tick packet, or None if the car has left the game
+    pub car_index: Option<usize>,
     pub location: Option<TargetLocation>,
+    pub destination: Option<Destination>,
     pub options: Options,
     pub shot: Option<Shot>,
     confirmed: bool,
@@ -274,8 +375,9 @@ impl Target {
     #[must_use]
     pub const fn new(target_left: Vec3A, target_right: Vec3A, car_index: usize, options: Options) -> Self {
         Self {
-            car_index,
+            car_index: Some(car_index),
             location: Some(TargetLocation::new(target_left, target_right)),
+            destination: None,
             options,
             shot: None,
             confirmed: false,
@@ -286,8 +388,22 @@ impl Target {
     #[must_use]
     pub const fn new_any(car_index: usize, options: Options) -> Self {
         Self {
-            car_index,
+            car_index: Some(car_index),
             location: None,
+            destination: None,
+            options,
+            shot: None,
+            confirmed: false,
+        }
+    }
+
+    #[inline]
+    #[must_use]
+    pub const fn new_destination(destination: Destination, car_index: usize, options: Options) -> Self {
+        Self {
+            car_index: Some(car_index),
+            location: None,
+            destination: Some(destination),
             options,
             shot: None,
             confirmed: false,
@@ -304,4 +420,10 @@ impl Target {
     pub const fn is_confirmed(&self) -> bool {
         self.confirmed
     }
+
+    /// The index of the target's car, if it's still in the game
+    #[inline]
+    pub fn car_index(&self) -> RlibResult<usize> {
+        self.car_index.ok_or(RlibError::CarGone)
+    }
 }
@@ -0,0 +1,278 @@
+use glam::Vec3A;
+
+/// The height of the ceiling in soccar and throwback arenas
+const SOCCAR_CEILING: f32 = 2044.;
+/// The radius of the curves between the floor, the walls, and the ceiling
+pub const TRANSITION_RADIUS: f32 = 256.;
+/// Half of the width of a soccar goal
+const SOCCAR_GOAL_HALF_WIDTH: f32 = 893.;
+/// Where the flat part of the back wall meets the corner, measured from the center of the wall
+const SOCCAR_BACK_WALL_END: f32 = 2944.;
+/// Where the flat part of the side wall meets the corner, measured from the center of the wall
+const SOCCAR_SIDE_WALL_END: f32 = 3968.;
+const THROWBACK_WALL_END: f32 = 5000.;
+
+pub const SOCCAR_WALLS: [Wall; 6] = [
+    Wall::new(Vec3A::X, 4096., -SOCCAR_SIDE_WALL_END, SOCCAR_SIDE_WALL_END, SOCCAR_CEILING),
+    Wall::new(
+        Vec3A::NEG_X,
+        4096.,
+        -SOCCAR_SIDE_WALL_END,
+        SOCCAR_SIDE_WALL_END,
+        SOCCAR_CEILING,
+    ),
+    // the back walls are split in two by the goals
+    Wall::new(
+        Vec3A::Y,
+        5120.,
+        -SOCCAR_BACK_WALL_END,
+        -SOCCAR_GOAL_HALF_WIDTH,
+        SOCCAR_CEILING,
+    ),
+    Wall::new(Vec3A::Y, 5120., SOCCAR_GOAL_HALF_WIDTH, SOCCAR_BACK_WALL_END, SOCCAR_CEILING),
+    Wall::new(
+        Vec3A::NEG_Y,
+        5120.,
+        -SOCCAR_BACK_WALL_END,
+        -SOCCAR_GOAL_HALF_WIDTH,
+        SOCCAR_CEILING,
+    ),
+    Wall::new(
+        Vec3A::NEG_Y,
+        5120.,
+        SOCCAR_GOAL_HALF_WIDTH,
+        SOCCAR_BACK_WALL_END,
+        SOCCAR_CEILING,
+    ),
+];
+
+pub const THROWBACK_WALLS: [Wall; 2] = [
+    Wall::new(Vec3A::X, 4096., -THROWBACK_WALL_END, THROWBACK_WALL_END, SOCCAR_CEILING),
+    Wall::new(Vec3A::NEG_X, 4096., -THROWBACK_WALL_END, THROWBACK_WALL_END, SOCCAR_CEILING),
+];
+
+/// A flat, vertical wall that the car can drive on
+///
+/// Paths onto the wall are found by unfolding it into the floor plane around its base,
+/// so a point `h` units up the wall becomes a point `h` units past the base of the wall,
+/// and the distance from the wall becomes the height above the floor.
+/// The ceiling above the wall is unfolded the same way around the top of the wall.
+/// This ignores the curves between the floor, wall, and ceiling, which makes paths slightly longer than they really are.
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Wall {
+    /// Points from the field towards the wall
+    outward: Vec3A,
+    /// The distance from the center of the field to the wall
+    distance: f32,
+    /// The drivable part of the wall, measured along the wall from its center
+    start: f32,
+    end: f32,
+    /// The height of the ceiling above the wall
+    ceiling: f32,
+}
+
+impl Wall {
+    #[inline]
+    #[must_use]
+    pub const fn new(outward: Vec3A, distance: f32, start: f32, end: f32, ceiling: f32) -> Self {
+        Self {
+            outward,
+            distance,
+            start,
+            end,
+            ceiling,
+        }
+    }
+
+    /// The height of the ceiling above the wall
+    #[inline]
+    #[must_use]
+    pub const fn ceiling(&self) -> f32 {
+        self.ceiling
+    }
+
+    /// The highest point of the flat part of the wall
+    #[inline]
+    #[must_use]
+    pub fn top(&self) -> f32 {
+        self.ceiling - TRANSITION_RADIUS
+    }
+
+    /// The direction that points up the unfolded wall, away from the field
+    #[inline]
+    #[must_use]
+    pub const fn outward(&self) -> Vec3A {
+        self.outward
+    }
+
+    /// The horizontal direction along the wall
+    #[inline]
+    fn tangent(&self) -> Vec3A {
+        Vec3A::new(-self.outward.y, self.outward.x, 0.)
+    }
+
+    /// The distance from the point to the surface of the wall
+    #[inline]
+    #[must_use]
+    pub fn distance_to(&self, p: Vec3A) -> f32 {
+        self.distance - p.dot(self.outward)
+    }
+
+    /// Checks if a point is closer to the wall than it is to the floor
+    #[inline]
+    #[must_use]
+    pub fn is_closer_than_floor(&self, p: Vec3A) -> bool {
+        p.z > self.distance_to(p)
+    }
+
+    /// Checks if the ball is on the flat part of the wall, and no further than `max_distance` from it
+    #[must_use]
+    pub fn is_touching(&self, ball_location: Vec3A, max_distance: f32) -> bool {
+        let along = ball_location.dot(self.tangent());
+
+        self.distance_to(ball_location) < max_distance
+            && self.is_closer_than_floor(ball_location)
+            && ball_location.z < self.top()
+            && (self.start..self.end).contains(&along)
+    }
+
+    /// The horizontal distance along the wall from its center to the point,
+    /// kept at least `margin` away from the ends of the wall
+    #[inline]
+    #[must_use]
+    pub fn clamp_along(&self, p: Vec3A, margin: f32) -> f32 {
+        p.dot(self.tangent()).clamp(self.start + margin, self.end - margin)
+    }
+
+    /// The point in the unfolded floor plane that's `along` the wall from its center,
+    /// and `up_wall` up the wall from its base, continuing onto the ceiling past the top of the wall
+    #[inline]
+    #[must_use]
+    pub fn unfolded_point(&self, along: f32, up_wall: f32) -> Vec3A {
+        self.tangent() * along + self.outward * (self.distance + up_wall)
+    }
+
+    /// Moves a point that's closer to the wall or the ceiling than it is to the floor into the floor plane
+    ///
+    /// Points that are closer to the floor are returned unchanged
+    #[must_use]
+    pub fn unfold(&self, p: Vec3A) -> Vec3A {
+        if !self.is_closer_than_floor(p) {
+            return p;
+        }
+
+        let distance_to_wall = self.distance_to(p);
+        let distance_to_ceiling = self.ceiling - p.z;
+
+        if distance_to_ceiling < distance_to_wall {
+            return self.unfolded_point(p.dot(self.tangent()), self.ceiling + distance_to_wall)
+                + Vec3A::Z * distance_to_ceiling;
+        }
+
+        self.unfolded_point(p.dot(self.tangent()), p.z) + Vec3A::Z * distance_to_wall
+    }
+
+    /// Rotates a direction on the wall into the floor plane
+    #[inline]
+    #[must_use]
+    pub fn unfold_vector(&self, v: Vec3A) -> Vec3A {
+        self.tangent() * v.dot(self.tangent()) + self.outward * v.z - Vec3A::Z * v.dot(self.outward)
+    }
+
+    /// The opposite of `unfold`, moving a point in the floor plane that is past the base of the wall back onto the wall,
+    /// or onto the ceiling if it's past the top of the wall
+    #[must_use]
+    pub fn fold(&self, p: Vec3A) -> Vec3A {
+        let up_wall = p.dot(self.outward) - self.distance;
+
+        if up_wall <= 0. {
+            return p;
+        }
+
+        let along = self.tangent() * p.dot(self.tangent());
+
+        if up_wall > self.ceiling {
+            return along + self.outward * (self.distance - (up_wall - self.ceiling)) + Vec3A::Z * (self.ceiling - p.z);
+        }
+
+        along + self.outward * (self.distance - p.z) + Vec3A::Z * up_wall
+    }
+
+    /// The opposite of `unfold_vector`
+    #[inline]
+    #[must_use]
+    pub fn fold_vector(&self, v: Vec3A) -> Vec3A {
+        self.tangent() * v.dot(self.tangent()) - self.outward * v.z + Vec3A::Z * v.dot(self.outward)
+    }
+
+    /// Checks if a point in the floor plane is on the unfolded wall, or the curve at its base,
+    /// with at least `margin` between the point and the edges of the wall
+    #[must_use]
+    pub fn contains_unfolded(&self, p: Vec3A, margin: f32) -> bool {
+        let up_wall = p.dot(self.outward) - self.distance;
+        let along = p.dot(self.tangent());
+
+        (-TRANSITION_RADIUS..self.top() - margin).contains(&up_wall)
+            && (self.start + margin..self.end - margin).contains(&along)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use glam::Vec3A;
+
+    use crate::wall::{Wall, SOCCAR_WALLS, THROWBACK_WALLS};
+
+    fn walls() -> impl Iterator<Item = &'static Wall> {
+        SOCCAR_WALLS.iter().chain(THROWBACK_WALLS.iter())
+    }
+
+    /// The point that's `along` the wall from its center, `up_wall` up it, and `off_wall` away from it
+    fn point_on(wall: &Wall, along: f32, up_wall: f32, off_wall: f32) -> Vec3A {
+        wall.tangent() * along + wall.outward() * (wall.distance - off_wall) + Vec3A::Z * up_wall
+    }
+
+    #[test]
+    pub fn unfold_fold_round_trip() {
+        for wall in walls() {
+            let along = (wall.start + wall.end) / 2.;
+
+            // on the wall, and on the ceiling above it
+            for p in [
+                point_on(wall, along, 1000., 93.),
+                point_on(wall, along, wall.ceiling() - 93., 500.),
+            ] {
+                let unfolded = wall.unfold(p);
+                assert!(unfolded.dot(wall.outward()) > wall.distance);
+                assert!((wall.fold(unfolded) - p).length() < 0.01);
+            }
+
+            // on the floor, where nothing changes
+            let p = point_on(wall, along, 93., 1000.);
+            assert_eq!(wall.unfold(p), p);
+            assert_eq!(wall.fold(p), p);
+
+            // past the base of the unfolded wall, and past the top of it
+            for up_wall in [500., wall.ceiling() + 300.] {
+                let p = wall.unfolded_point(along, up_wall) + Vec3A::Z * 93.;
+                assert!((wall.unfold(wall.fold(p)) - p).length() < 0.01);
+            }
+
+            let v = Vec3A::new(300., -200., 1000.);
+            assert!((wall.fold_vector(wall.unfold_vector(v)) - v).length() < 0.01);
+        }
+    }
+
+    #[test]
+    pub fn unfold_up_the_wall() {
+        // driving 1000uu up the wall is the same as driving 1000uu past its base
+        for wall in walls() {
+            let along = (wall.start + wall.end) / 2.;
+            let unfolded = wall.unfold(point_on(wall, along, 1000., 17.));
+
+            assert!((unfolded - (wall.unfolded_point(along, 1000.) + Vec3A::Z * 17.)).length() < 0.01);
+            assert!(wall.contains_unfolded(unfolded, 100.));
+            assert!(wall.unfold_vector(Vec3A::Z).dot(wall.outward()) > 0.99);
+        }
+    }
+}
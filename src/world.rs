@@ -0,0 +1,2203 @@
+use std::sync::Arc;
+
+use combo_vec::{rearr, ReArr};
+use glam::Vec3A;
+#[cfg(feature = "python")]
+use pyo3::prelude::*;
+use rl_ball_sym::simulation::{
+    ball::{Ball, Predictions},
+    game::Game,
+};
+
+use crate::{
+    air::{self, AerialTargetInfo, CeilingTargetInfo},
+    analyzer::*,
+    boost::{self, standard_boost_pads, BoostPad},
+    car::{turn_radius, Arena, Car, Obstacle, State},
+    constants::*,
+    error::{RlibError, RlibResult},
+    field::FieldGrid,
+    ground::{self, GroundTargetInfo, Turning},
+    hit::{self, rotmat_from, Contact},
+    pytypes::*,
+    shot::{AirBasedShot, CeilingShot, Destination, GroundBasedShot, Options, Shot, Target},
+    utils::*,
+    BoostAmount, Mutators,
+};
+
+/// A self-contained analysis session
+///
+/// Owns the game, ball prediction, cars, mutators and targets
+#[cfg_attr(feature = "python", pyclass)]
+#[derive(Clone, Debug)]
+pub struct World {
+    cars: ReArr<Car, 8>,
+    ball_struct: Predictions,
+    gravity: Vec3A,
+    game_time: f32,
+    game_state: GameState,
+    game: Option<Game>,
+    ball: Ball,
+    mutators: Mutators,
+    targets: ReArr<Option<Target>, 16>,
+    boost_pads: Vec<BoostPad>,
+    /// The boost pad layout from the last field info, for arenas that don't have one built in
+    field_boost_pads: Option<Vec<BoostPad>>,
+    arena: Arena,
+    field: Option<Arc<FieldGrid>>,
+}
+
+impl Default for World {
+    #[inline]
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl World {
+    #[inline]
+    #[must_use]
+    pub const fn new() -> Self {
+        Self {
+            cars: rearr![],
+            ball_struct: Predictions::new(),
+            gravity: Vec3A::ZERO,
+            game_time: 0.,
+            game_state: GameState::new(),
+            game: None,
+            ball: Ball::const_default(),
+            mutators: Mutators::new(),
+            targets: rearr![],
+            boost_pads: Vec::new(),
+            field_boost_pads: None,
+            arena: Arena::Soccar,
+            field: None,
+        }
+    }
+
+    #[inline]
+    fn set_game(&mut self, (game, ball): (Game, Ball), boost_pads: Option<Vec<BoostPad>>, arena: Arena) {
+        self.field = Some(Arc::new(FieldGrid::from_game(&game, ball)));
+        self.game = Some(game);
+        self.ball = ball;
+        self.boost_pads = boost_pads.or_else(|| self.field_boost_pads.clone()).unwrap_or_default();
+        self.arena = arena;
+    }
+
+    fn add_target(
+        &mut self,
+        car_index: usize,
+        options: Option<TargetOptions>,
+        new_target: impl FnOnce(Options) -> Target,
+    ) -> RlibResult<usize> {
+        let num_slices = self.ball_struct.len();
+
+        if num_slices == 0 {
+            return Err(RlibError::NoSlices);
+        }
+
+        let options = Options::new(options, num_slices);
+
+        self.cars.get_mut(car_index).ok_or(RlibError::NoCar)?.init(
+            self.gravity.z,
+            num_slices,
+            self.mutators,
+            &self.boost_pads,
+            self.field.clone(),
+            self.arena,
+        );
+
+        let target = Some(new_target(options));
+
+        let target_position = self.targets.iter().position(Option::is_none);
+        let target_index = if let Some(i) = target_position {
+            self.targets[i] = target;
+            i
+        } else {
+            self.targets.push(target);
+            self.targets.len() - 1
+        };
+
+        Ok(target_index)
+    }
+
+    fn get_target(&self, target_index: usize) -> RlibResult<&Target> {
+        self.targets
+            .get(target_index)
+            .ok_or(RlibError::NoTarget)?
+            .as_ref()
+            .ok_or(RlibError::NoTarget)
+    }
+
+    fn get_target_mut(&mut self, target_index: usize) -> RlibResult<&mut Target> {
+        self.targets
+            .get_mut(target_index)
+            .ok_or(RlibError::NoTarget)?
+            .as_mut()
+            .ok_or(RlibError::NoTarget)
+    }
+
+    /// Returns None if the car can't take any shots at all
+    fn get_analyzer(&self, target: &Target, mut may_shoot: [bool; 6]) -> RlibResult<Option<Analyzer<'_>>> {
+        // ceiling and dodge shots are only searched for if the target asked for them
+        may_shoot[4] &= target.options.ceiling_shots;
+        may_shoot[5] &= target.options.dodge_shots;
+
+        if !may_shoot.iter().any(|&x| x) {
+            return Err(RlibError::NoShotSelected);
+        }
+
+        let car_index = target.car_index()?;
+        let car = self.cars.get(car_index).ok_or(RlibError::NoCar)?;
+
+        // nothing can be hit during kickoff countdowns, goal replays, or after the match ends,
+        // but cars can still drive to locations, and targets can ask to keep searching
+        let play_stopped =
+            !self.game_state.is_playing() && target.destination.is_none() && !target.options.search_while_stopped;
+
+        if play_stopped
+            || car.car_state == State::Demolished
+            || self.ball_struct.is_empty()
+            || car.time_to_land >= self.ball_struct.last().map(|slice| slice.time).unwrap_or_default()
+        {
+            return Ok(None);
+        }
+
+        let (max_speed, max_turn_radius) = if target.options.use_absolute_max_values {
+            (Some(MAX_SPEED), Some(turn_radius(MAX_SPEED)))
+        } else {
+            (None, None)
+        };
+
+        let obstacles = self
+            .cars
+            .iter()
+            .enumerate()
+            .filter(|&(i, other)| {
+                let is_teammate = other.team == car.team;
+
+                i != car_index
+                    && other.car_state != State::Demolished
+                    && ((is_teammate && target.options.avoid_teammates) || (!is_teammate && target.options.avoid_opponents))
+            })
+            .map(|(_, other)| Obstacle::from(car, other))
+            .collect();
+
+        Ok(Some(Analyzer {
+            max_speed,
+            max_turn_radius,
+            gravity: self.gravity,
+            may: may_shoot,
+            forwards_only: target.options.forwards_only,
+            simulate_aerials: target.options.simulate_aerials,
+            obstacles,
+            car_front_length: (car.hitbox_offset.x + car.hitbox.length) / 2.,
+            car,
+        }))
+    }
+
+    pub fn load_standard(&mut self) {
+        self.set_game(
+            rl_ball_sym::compressed::load_standard(),
+            Some(standard_boost_pads()),
+            Arena::Soccar,
+        );
+    }
+
+    pub fn load_dropshot(&mut self) {
+        self.set_game(rl_ball_sym::compressed::load_dropshot(), Some(Vec::new()), Arena::Dropshot);
+    }
+
+    /// The boost pad layout isn't built in, so it comes from the last call to `set_field_info`
+    pub fn load_hoops(&mut self) {
+        self.set_game(rl_ball_sym::compressed::load_hoops(), None, Arena::Hoops);
+    }
+
+    /// The boost pad layout isn't built in, so it comes from the last call to `set_field_info`
+    pub fn load_standard_throwback(&mut self) {
+        self.set_game(rl_ball_sym::compressed::load_standard_throwback(), None, Arena::Throwback);
+    }
+
+    /// Sets the boost pad layout from RLBot's field info
+    ///
+    /// This can be called before or after loading the arena,
+    /// and the layout is kept for loading hoops or throwback later
+    #[inline]
+    pub fn set_field_info(&mut self, field_info: GameFieldInfo) {
+        self.boost_pads = field_info.into();
+        self.field_boost_pads = Some(self.boost_pads.clone());
+    }
+
+    #[inline]
+    pub fn set_mutator_settings(&mut self, mutators: Mutators) {
+        self.mutators = mutators;
+    }
+
+    pub fn tick(&mut self, packet: GamePacket, prediction_time: Option<f32>) -> RlibResult<()> {
+        self.targets.iter_mut().for_each(|target| {
+            if matches!(target, Some(t) if !t.is_confirmed()) {
+                *target = None;
+            }
+        });
+
+        let game = self.game.as_mut().ok_or(RlibError::NoGame)?;
+
+        // Get general game information
+        self.game_time = packet.game_info.seconds_elapsed;
+        self.game_state = packet.game_info.into();
+        game.gravity.z = packet.game_info.world_gravity_z;
+        self.gravity = game.gravity;
+
+        // Get information about the ball
+        let mut ball = self.ball;
+        ball.update(
+            packet.game_info.seconds_elapsed,
+            packet.game_ball.physics.location.into(),
+            packet.game_ball.physics.velocity.into(),
+            packet.game_ball.physics.angular_velocity.into(),
+        );
+
+        let radius = packet.game_ball.collision_shape.get_radius();
+
+        // check if the new radius is different
+        // if is is, set it
+        if (ball.radius() - radius).abs() > 0.1 {
+            ball.set_radius(radius, radius + 1.9);
+        }
+
+        // Predict future information about the ball
+        self.ball_struct = ball.get_ball_prediction_struct_for_time(game, prediction_time.unwrap_or(6.));
+
+        // Get information about the cars on the field
+        // the cars are matched up by their spawn ids, because their indices change when players join or leave
+        let mut old_cars: Vec<Option<Car>> = std::mem::replace(&mut self.cars, rearr![])
+            .into_vec()
+            .into_iter()
+            .map(Some)
+            .collect();
+        let mut new_indices = vec![None; old_cars.len()];
+
+        // if the spawn ids can't tell the cars apart, fall back to their indices for this tick
+        let use_spawn_ids = has_unique_spawn_ids(old_cars.iter().flatten().map(|car| car.spawn_id))
+            && has_unique_spawn_ids(packet.game_cars.iter().take(packet.num_cars).map(|car| car.spawn_id));
+
+        for (i, pycar) in packet.game_cars.into_iter().take(packet.num_cars).enumerate() {
+            let old_index = if use_spawn_ids {
+                old_cars
+                    .iter()
+                    .position(|car| matches!(car, Some(car) if car.spawn_id == pycar.spawn_id))
+            } else {
+                (i < old_cars.len()).then_some(i)
+            };
+
+            let mut car = old_index
+                .and_then(|i| {
+                    new_indices[i] = Some(self.cars.len());
+                    old_cars[i].take()
+                })
+                .unwrap_or_else(Car::new);
+
+            car.update(pycar, packet.game_info.seconds_elapsed);
+            self.cars.push(car);
+        }
+
+        // keep the targets following their cars, or mark the cars as gone
+        for target in self.targets.iter_mut().flatten() {
+            target.car_index = target.car_index.and_then(|i| new_indices.get(i).copied().flatten());
+        }
+
+        // Get information about the boost pads, if they match the known layout
+        if packet.num_boost == self.boost_pads.len() {
+            for (pad, state) in self.boost_pads.iter_mut().zip(packet.game_boosts) {
+                pad.update(state);
+            }
+        }
+
+        Ok(())
+    }
+
+    pub fn get_slice(&self, slice_time: f32) -> BallSlice {
+        let slice_num = ((slice_time - self.game_time) * TPS).round() as usize;
+        self.get_slice_index(slice_num)
+    }
+
+    pub fn get_slice_index(&self, slice_num: usize) -> BallSlice {
+        self.ball_struct[slice_num.clamp(0, self.ball_struct.len() - 1)].into()
+    }
+
+    pub fn get_num_ball_slices(&self) -> usize {
+        self.ball_struct.len()
+    }
+
+    /// If the match is paused, in a kickoff, in overtime, or over
+    ///
+    /// No shots are found while the round isn't active or after the match has ended,
+    /// except for location targets and targets with `search_while_stopped`
+    #[inline]
+    pub const fn get_game_state(&self) -> GameState {
+        self.game_state
+    }
+
+    fn get_goal_posts(&self, team: u8) -> RlibResult<(PyVec3A, PyVec3A)> {
+        let (left, right) = self.arena.goal_posts(team).ok_or(RlibError::NoGoals)?;
+        Ok((left.into(), right.into()))
+    }
+
+    /// The left and right posts of the goal that the car defends, for passing to `new_target`
+    pub fn get_own_goal(&self, car_index: usize) -> RlibResult<(PyVec3A, PyVec3A)> {
+        let car = self.cars.get(car_index).ok_or(RlibError::NoCar)?;
+        self.get_goal_posts(car.team)
+    }
+
+    /// The left and right posts of the goal that the car attacks, for passing to `new_target`
+    pub fn get_opponent_goal(&self, car_index: usize) -> RlibResult<(PyVec3A, PyVec3A)> {
+        let car = self.cars.get(car_index).ok_or(RlibError::NoCar)?;
+        self.get_goal_posts(u8::from(car.team == 0))
+    }
+
+    pub fn new_target(
+        &mut self,
+        left_target: [f32; 3],
+        right_target: [f32; 3],
+        car_index: usize,
+        options: Option<TargetOptions>,
+    ) -> RlibResult<usize> {
+        self.add_target(car_index, options, |options| {
+            Target::new(left_target.into(), right_target.into(), car_index, options)
+        })
+    }
+
+    pub fn new_any_target(&mut self, car_index: usize, options: Option<TargetOptions>) -> RlibResult<usize> {
+        self.add_target(car_index, options, |options| Target::new_any(car_index, options))
+    }
+
+    /// Creates a target for driving to a point on the field instead of hitting the ball
+    ///
+    /// If `arrival_time` is given, only paths that arrive at exactly that game time are considered,
+    /// and it has to be after the current tick and within the ball prediction
+    pub fn new_location_target(
+        &mut self,
+        car_index: usize,
+        location: [f32; 3],
+        arrival_yaw: Option<f32>,
+        arrival_time: Option<f32>,
+        options: Option<TargetOptions>,
+    ) -> RlibResult<usize> {
+        let arrival_slice = arrival_time
+            .map(|arrival_time| {
+                let ticks_remaining = ((arrival_time - self.game_time) * TPS).round();
+
+                if ticks_remaining < 1. {
+                    return Err(RlibError::NoTimeRemaining);
+                }
+
+                if ticks_remaining > self.ball_struct.len() as f32 {
+                    return Err(RlibError::NoSlices);
+                }
+
+                // the first slice is one tick after the current one
+                Ok(ticks_remaining as usize - 1)
+            })
+            .transpose()?;
+
+        self.add_target(car_index, options, |mut options| {
+            if let Some(slice_num) = arrival_slice {
+                options.min_slice = slice_num;
+                options.max_slice = slice_num + 1;
+            }
+
+            Target::new_destination(Destination::new(location.into(), arrival_yaw), car_index, options)
+        })
+    }
+
+    pub fn confirm_target(&mut self, target_index: usize) -> RlibResult<()> {
+        let target = self.get_target_mut(target_index)?;
+
+        if target.shot.is_none() {
+            return Err(RlibError::NoShot);
+        }
+
+        target.confirm();
+        Ok(())
+    }
+
+    pub fn remove_target(&mut self, target_index: usize) {
+        if self.targets.get(target_index).is_none() {
+            return;
+        }
+
+        self.targets[target_index] = None;
+    }
+
+    pub fn print_targets(&self) {
+        let mut out = Vec::with_capacity(self.targets.len());
+
+        for target in self.targets.iter() {
+            out.push(match target {
+                Some(t) => match &t.shot {
+                    Some(s) => s.time().to_string(),
+                    None => String::from("No shot"),
+                },
+                None => String::from("None"),
+            });
+        }
+
+        println!("[{}]", out.join(", "));
+    }
+
+    pub fn get_targets_length(&self) -> usize {
+        self.targets.len()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_shot_with_target(
+        &mut self,
+        target_index: usize,
+        temporary: Option<bool>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> RlibResult<BasicShotInfo> {
+        let temporary = temporary.unwrap_or(false);
+
+        let (found_shot, basic_shot_info) = {
+            let may_shoot = get_may_shoot(
+                may_ground_shot,
+                may_jump_shot,
+                may_double_jump_shot,
+                may_aerial_shot,
+                only,
+                may_ceiling_shot,
+                may_dodge_shot,
+            )?;
+            let target = self.get_target(target_index)?;
+
+            let Some(analyzer) = self.get_analyzer(target, may_shoot)? else {
+                return Ok(BasicShotInfo::not_found());
+            };
+
+            match analyze_shot(
+                &analyzer,
+                &self.ball_struct,
+                target,
+                self.mutators,
+                temporary,
+                self.game_time,
+                self.arena,
+            ) {
+                Some(a) => a,
+                None => return Ok(BasicShotInfo::not_found()),
+            }
+        };
+
+        if !temporary {
+            self.get_target_mut(target_index)?.shot = Some(found_shot);
+        }
+
+        Ok(basic_shot_info)
+    }
+
+    /// Runs the same search as `get_shot_with_target`, but reports why each ball slice was rejected
+    ///
+    /// The search stops at the first viable slice unless the target was created with `all`
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_shot_diagnostics(
+        &self,
+        target_index: usize,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> RlibResult<Vec<SliceDiagnostic>> {
+        let may_shoot = get_may_shoot(
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?;
+        let target = self.get_target(target_index)?;
+
+        let Some(analyzer) = self.get_analyzer(target, may_shoot)? else {
+            return Ok(Vec::new());
+        };
+
+        let slices = &self.ball_struct[target.options.min_slice..target.options.max_slice];
+        let mut diagnostics = Vec::with_capacity(slices.len());
+
+        for (i, ball) in slices.iter().enumerate() {
+            let slice_num = target.options.min_slice + i;
+
+            if target.destination.is_none() && is_ball_out_of_field(ball, self.arena) {
+                diagnostics.push(SliceDiagnostic {
+                    slice_num,
+                    time: ball.time,
+                    shot_type: None,
+                    rejection: Some(ShotRejection::BallOutOfField),
+                });
+                break;
+            }
+
+            let result = analyze_slice(&analyzer, ball, slice_num, target, self.mutators, true, self.game_time);
+            let found = result.is_ok();
+
+            diagnostics.push(SliceDiagnostic {
+                slice_num,
+                time: ball.time,
+                shot_type: match &result {
+                    // aerials can turn into ceiling shots
+                    Ok((_, basic_shot_info)) => basic_shot_info.shot_type,
+                    Err(_) if target.destination.is_some() => Some(ShotType::Ground),
+                    Err(_) => analyzer.get_shot_type(ball.location, ball.time - self.game_time).ok(),
+                },
+                rejection: result.err(),
+            });
+
+            if found && !target.options.all {
+                break;
+            }
+        }
+
+        Ok(diagnostics)
+    }
+
+    /// Finds every viable shot in the target's range of slices, and ranks them from best to worst
+    ///
+    /// Returns up to `count` shots, or all of them if `count` isn't set
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_ranked_shots(
+        &self,
+        target_index: usize,
+        count: Option<usize>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> RlibResult<Vec<RankedShot>> {
+        let may_shoot = get_may_shoot(
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?;
+        let target = self.get_target(target_index)?;
+
+        let Some(analyzer) = self.get_analyzer(target, may_shoot)? else {
+            return Ok(Vec::new());
+        };
+
+        Ok(rank_shots(
+            &analyzer,
+            &self.ball_struct,
+            target,
+            self.mutators,
+            self.game_time,
+            self.arena,
+        )
+        .into_iter()
+        .take(count.unwrap_or(usize::MAX))
+        .map(|(_, ranked_shot)| ranked_shot)
+        .collect())
+    }
+
+    /// Finds the earliest ball slice that every car can hit,
+    /// with the same search that `get_shot_with_target` runs for a target from `new_any_target`
+    ///
+    /// The options apply to every car, except that `all` and `rank` are ignored
+    #[allow(clippy::too_many_arguments)]
+    pub fn get_all_car_intercepts(
+        &mut self,
+        options: Option<TargetOptions>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> RlibResult<Vec<CarIntercept>> {
+        let num_slices = self.ball_struct.len();
+
+        if num_slices == 0 {
+            return Err(RlibError::NoSlices);
+        }
+
+        let may_shoot = get_may_shoot(
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?;
+
+        // the earliest slice is wanted, not the best one
+        let options = Options {
+            all: false,
+            rank: false,
+            ..Options::new(options, num_slices)
+        };
+
+        for car in self.cars.iter_mut() {
+            car.init(
+                self.gravity.z,
+                num_slices,
+                self.mutators,
+                &self.boost_pads,
+                self.field.clone(),
+                self.arena,
+            );
+        }
+
+        self.cars
+            .iter()
+            .enumerate()
+            .map(|(car_index, car)| {
+                let target = Target::new_any(car_index, options);
+
+                let intercept = self.get_analyzer(&target, may_shoot)?.and_then(|analyzer| {
+                    self.ball_struct[options.min_slice..options.max_slice]
+                        .iter()
+                        .enumerate()
+                        .take_while(|(_, ball)| !is_ball_out_of_field(ball, self.arena))
+                        .find_map(|(i, ball)| {
+                            let slice_num = options.min_slice + i;
+                            analyze_slice(&analyzer, ball, slice_num, &target, self.mutators, true, self.game_time)
+                                .ok()
+                                .map(|(_, basic_shot_info)| (slice_num, basic_shot_info))
+                        })
+                });
+
+                Ok(CarIntercept {
+                    car_index,
+                    team: car.team,
+                    slice_num: intercept.map(|(slice_num, _)| slice_num),
+                    time: intercept.map(|(_, basic_shot_info)| basic_shot_info.time),
+                    shot_type: intercept.and_then(|(_, basic_shot_info)| basic_shot_info.shot_type),
+                })
+            })
+            .collect()
+    }
+
+    /// Get the routes to every boost pad that the car can reach, soonest first
+    ///
+    /// Fails if the arena has boost pads, but their layout isn't known because `set_field_info` wasn't called
+    pub fn get_boost_pad_routes(
+        &mut self,
+        car_index: usize,
+        options: Option<BoostPadOptions>,
+    ) -> RlibResult<Vec<BoostPadRoute>> {
+        let num_slices = self.ball_struct.len();
+
+        if num_slices == 0 {
+            return Err(RlibError::NoSlices);
+        }
+
+        if self.boost_pads.is_empty() && self.arena.has_boost_pads() {
+            return Err(RlibError::NoBoostPads);
+        }
+
+        let car = self.cars.get_mut(car_index).ok_or(RlibError::NoCar)?;
+        car.init(
+            self.gravity.z,
+            num_slices,
+            self.mutators,
+            &self.boost_pads,
+            self.field.clone(),
+            self.arena,
+        );
+
+        Ok(boost::get_boost_pad_routes(
+            car,
+            &self.boost_pads,
+            options.unwrap_or_default(),
+            self.mutators,
+        ))
+    }
+
+    pub fn get_data_for_shot_with_target(&self, target_index: usize) -> RlibResult<AdvancedShotInfo> {
+        let target = self.get_target(target_index)?;
+        let shot = target.shot.as_ref().ok_or(RlibError::NoShot)?;
+
+        let time_remaining = shot.time() - self.game_time;
+
+        if time_remaining < 0. {
+            return Err(RlibError::NoTimeRemaining);
+        }
+
+        let car = self.cars.get(target.car_index()?).ok_or(RlibError::NoCar)?;
+
+        let slice_num = ((time_remaining * TPS).round() as usize).clamp(1, self.ball_struct.len()) - 1;
+        let ball = self.ball_struct[slice_num];
+
+        if target.destination.is_none() && ball.location.distance(shot.ball_location()) > car.hitbox.width {
+            return Err(RlibError::BallChanged);
+        }
+
+        match shot {
+            Shot::GroundBased(shot_details) => {
+                let shot_info = AdvancedShotInfo::get_from_ground(car, shot_details).ok_or(RlibError::StrayedFromPath)?;
+
+                if car.max_speed[slice_num] * (time_remaining + 0.1) >= shot_info.get_distance_remaining() {
+                    Ok(shot_info)
+                } else {
+                    Err(RlibError::BadAcceleration)
+                }
+            }
+            Shot::AirBased(shot_details) => {
+                let shot_info = AdvancedShotInfo::get_from_air(car, shot_details);
+
+                if self.is_air_shot_valid(car, shot_details, time_remaining, target.options.simulate_aerials) {
+                    Ok(shot_info)
+                } else {
+                    Err(RlibError::BadAcceleration)
+                }
+            }
+            Shot::Ceiling(shot_details) => {
+                if car.car_state != State::Grounded {
+                    let shot_info = AdvancedShotInfo::get_from_ceiling_drop(car, shot_details);
+
+                    return if self.is_air_shot_valid(car, &shot_details.air, time_remaining, target.options.simulate_aerials)
+                    {
+                        Ok(shot_info)
+                    } else {
+                        Err(RlibError::BadAcceleration)
+                    };
+                }
+
+                let shot_info =
+                    AdvancedShotInfo::get_from_ceiling_path(car, shot_details).ok_or(RlibError::StrayedFromPath)?;
+
+                let leave_time_remaining = shot_details.leave_time - self.game_time;
+                let leave_slice_num = ((leave_time_remaining * TPS).round() as usize).clamp(1, self.ball_struct.len()) - 1;
+
+                if leave_time_remaining >= 0.
+                    && car.max_speed[leave_slice_num] * (leave_time_remaining + 0.1) >= shot_info.get_distance_remaining()
+                {
+                    Ok(shot_info)
+                } else {
+                    Err(RlibError::BadAcceleration)
+                }
+            }
+        }
+    }
+
+    /// The controls for this tick that follow the found shot
+    pub fn get_controls_for_shot(&mut self, target_index: usize) -> RlibResult<ControllerState> {
+        let car_index = self.get_target(target_index)?.car_index()?;
+
+        // the orientation of the car has to be up to date to steer
+        self.cars.get_mut(car_index).ok_or(RlibError::NoCar)?.init(
+            self.gravity.z,
+            self.ball_struct.len(),
+            self.mutators,
+            &self.boost_pads,
+            self.field.clone(),
+            self.arena,
+        );
+
+        let target = self.get_target(target_index)?;
+        let car = self.cars.get(car_index).ok_or(RlibError::NoCar)?;
+
+        // letting go of the throttle on the ceiling drops the car off of it
+        if let Some(Shot::Ceiling(shot)) = &target.shot {
+            if car.car_state == State::Grounded && self.game_time >= shot.leave_time {
+                return Ok(ControllerState::default());
+            }
+        }
+
+        let shot_info = self.get_data_for_shot_with_target(target_index)?;
+
+        Ok(match target.shot.as_ref().ok_or(RlibError::NoShot)? {
+            Shot::GroundBased(shot) => {
+                ground::get_controls(car, shot, &shot_info, shot.time - self.game_time, self.mutators)
+            }
+            Shot::AirBased(shot) => air::get_controls(car, shot, self.gravity, self.mutators, shot.time - self.game_time),
+            Shot::Ceiling(shot) if car.car_state == State::Grounded => {
+                ground::get_controls(car, &shot.ground, &shot_info, shot.leave_time - self.game_time, self.mutators)
+            }
+            Shot::Ceiling(shot) => {
+                air::get_controls(car, &shot.air, self.gravity, self.mutators, shot.air.time - self.game_time)
+            }
+        })
+    }
+
+    /// Predicts what the ball will do after the car hits it with the found shot
+    pub fn get_post_hit_prediction(
+        &self,
+        target_index: usize,
+        prediction_time: Option<f32>,
+    ) -> RlibResult<PostHitPrediction> {
+        let game = self.game.as_ref().ok_or(RlibError::NoGame)?;
+        let target = self.get_target(target_index)?;
+
+        // driving to a location doesn't touch the ball
+        if target.destination.is_some() {
+            return Err(RlibError::NoShot);
+        }
+
+        let shot = target.shot.as_ref().ok_or(RlibError::NoShot)?;
+        let time_remaining = shot.time() - self.game_time;
+
+        if time_remaining < 0. {
+            return Err(RlibError::NoTimeRemaining);
+        }
+
+        let car = self.cars.get(target.car_index()?).ok_or(RlibError::NoCar)?;
+
+        let slice_num = ((time_remaining * TPS).round() as usize).clamp(1, self.ball_struct.len()) - 1;
+        let mut ball = self.ball_struct[slice_num];
+
+        let velocity = get_contact(car, shot, &ball, time_remaining).hit(&ball, car);
+        ball.update(ball.time, ball.location, velocity, ball.angular_velocity);
+
+        let predictions = ball.get_ball_prediction_struct_for_time(game, prediction_time.unwrap_or(6.));
+        let (outcome, outcome_time) = hit::predict_outcome(&predictions, self.arena, car.team)
+            .map_or((None, None), |(outcome, time)| (Some(outcome), time));
+
+        Ok(PostHitPrediction {
+            time: ball.time,
+            location: get_tuple_from_vec3(ball.location),
+            velocity: get_tuple_from_vec3(velocity),
+            outcome,
+            outcome_time,
+        })
+    }
+
+    fn is_air_shot_valid(&self, car: &Car, shot_details: &AirBasedShot, time_remaining: f32, simulate: bool) -> bool {
+        if simulate {
+            return air::simulate_validate(car, shot_details, self.gravity, self.mutators, time_remaining);
+        }
+
+        let pending_jump = air::PendingJump::new(car, shot_details.jump_type, time_remaining);
+
+        let vf_base = car.velocity + self.gravity * time_remaining + pending_jump.velocity;
+        let xf_base =
+            car.velocity * time_remaining + self.gravity * 0.5 * time_remaining.powi(2) + pending_jump.displacement;
+
+        air::partial_validate(
+            shot_details.final_target,
+            car.location + xf_base,
+            vf_base,
+            self.mutators.boost_amount,
+            self.mutators.boost_accel,
+            f32::from(car.boost),
+            shot_details.time - self.game_time,
+        )
+    }
+}
+
+#[cfg(feature = "python")]
+#[pymethods]
+impl World {
+    #[new]
+    #[inline]
+    const fn __new__() -> Self {
+        Self::new()
+    }
+
+    #[inline]
+    fn __copy__(&self) -> Self {
+        self.clone()
+    }
+
+    #[pyo3(name = "load_standard")]
+    fn py_load_standard(&mut self) {
+        self.load_standard();
+    }
+
+    #[pyo3(name = "load_dropshot")]
+    fn py_load_dropshot(&mut self) {
+        self.load_dropshot();
+    }
+
+    #[pyo3(name = "load_hoops")]
+    fn py_load_hoops(&mut self) {
+        self.load_hoops();
+    }
+
+    #[pyo3(name = "load_standard_throwback")]
+    fn py_load_standard_throwback(&mut self) {
+        self.load_standard_throwback();
+    }
+
+    #[pyo3(name = "set_mutator_settings")]
+    fn py_set_mutator_settings(&mut self, mutators: &PyAny) -> PyResult<()> {
+        self.set_mutator_settings(Mutators::try_from(mutators)?);
+
+        Ok(())
+    }
+
+    #[pyo3(name = "set_field_info")]
+    fn py_set_field_info(&mut self, field_info: GameFieldInfo) {
+        self.set_field_info(field_info);
+    }
+
+    #[pyo3(name = "tick")]
+    fn py_tick(&mut self, packet: GamePacket, prediction_time: Option<f32>) -> PyResult<()> {
+        Ok(self.tick(packet, prediction_time)?)
+    }
+
+    #[pyo3(name = "get_slice")]
+    fn py_get_slice(&self, slice_time: f32) -> BallSlice {
+        self.get_slice(slice_time)
+    }
+
+    #[pyo3(name = "get_slice_index")]
+    fn py_get_slice_index(&self, slice_num: usize) -> BallSlice {
+        self.get_slice_index(slice_num)
+    }
+
+    #[pyo3(name = "get_num_ball_slices")]
+    fn py_get_num_ball_slices(&self) -> usize {
+        self.get_num_ball_slices()
+    }
+
+    #[pyo3(name = "get_game_state")]
+    fn py_get_game_state(&self) -> GameState {
+        self.get_game_state()
+    }
+
+    #[pyo3(name = "get_own_goal")]
+    fn py_get_own_goal(&self, car_index: usize) -> PyResult<(PyVec3A, PyVec3A)> {
+        Ok(self.get_own_goal(car_index)?)
+    }
+
+    #[pyo3(name = "get_opponent_goal")]
+    fn py_get_opponent_goal(&self, car_index: usize) -> PyResult<(PyVec3A, PyVec3A)> {
+        Ok(self.get_opponent_goal(car_index)?)
+    }
+
+    #[pyo3(name = "new_target")]
+    fn py_new_target(
+        &mut self,
+        left_target: [f32; 3],
+        right_target: [f32; 3],
+        car_index: usize,
+        options: Option<TargetOptions>,
+    ) -> PyResult<usize> {
+        Ok(self.new_target(left_target, right_target, car_index, options)?)
+    }
+
+    #[pyo3(name = "new_any_target")]
+    fn py_new_any_target(&mut self, car_index: usize, options: Option<TargetOptions>) -> PyResult<usize> {
+        Ok(self.new_any_target(car_index, options)?)
+    }
+
+    #[pyo3(name = "new_location_target")]
+    fn py_new_location_target(
+        &mut self,
+        car_index: usize,
+        location: [f32; 3],
+        arrival_yaw: Option<f32>,
+        arrival_time: Option<f32>,
+        options: Option<TargetOptions>,
+    ) -> PyResult<usize> {
+        Ok(self.new_location_target(car_index, location, arrival_yaw, arrival_time, options)?)
+    }
+
+    #[pyo3(name = "confirm_target")]
+    fn py_confirm_target(&mut self, target_index: usize) -> PyResult<()> {
+        Ok(self.confirm_target(target_index)?)
+    }
+
+    #[pyo3(name = "remove_target")]
+    fn py_remove_target(&mut self, target_index: usize) {
+        self.remove_target(target_index);
+    }
+
+    #[pyo3(name = "print_targets")]
+    fn py_print_targets(&self) {
+        self.print_targets();
+    }
+
+    #[pyo3(name = "get_targets_length")]
+    fn py_get_targets_length(&self) -> usize {
+        self.get_targets_length()
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "get_shot_with_target")]
+    fn py_get_shot_with_target(
+        &mut self,
+        target_index: usize,
+        temporary: Option<bool>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> PyResult<BasicShotInfo> {
+        Ok(self.get_shot_with_target(
+            target_index,
+            temporary,
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "get_shot_diagnostics")]
+    fn py_get_shot_diagnostics(
+        &self,
+        target_index: usize,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> PyResult<Vec<SliceDiagnostic>> {
+        Ok(self.get_shot_diagnostics(
+            target_index,
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "get_ranked_shots")]
+    fn py_get_ranked_shots(
+        &self,
+        target_index: usize,
+        count: Option<usize>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> PyResult<Vec<RankedShot>> {
+        Ok(self.get_ranked_shots(
+            target_index,
+            count,
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?)
+    }
+
+    #[allow(clippy::too_many_arguments)]
+    #[pyo3(name = "get_all_car_intercepts")]
+    fn py_get_all_car_intercepts(
+        &mut self,
+        options: Option<TargetOptions>,
+        may_ground_shot: Option<bool>,
+        may_jump_shot: Option<bool>,
+        may_double_jump_shot: Option<bool>,
+        may_aerial_shot: Option<bool>,
+        only: Option<bool>,
+        may_ceiling_shot: Option<bool>,
+        may_dodge_shot: Option<bool>,
+    ) -> PyResult<Vec<CarIntercept>> {
+        Ok(self.get_all_car_intercepts(
+            options,
+            may_ground_shot,
+            may_jump_shot,
+            may_double_jump_shot,
+            may_aerial_shot,
+            only,
+            may_ceiling_shot,
+            may_dodge_shot,
+        )?)
+    }
+
+    #[pyo3(name = "get_boost_pad_routes")]
+    fn py_get_boost_pad_routes(
+        &mut self,
+        car_index: usize,
+        options: Option<BoostPadOptions>,
+    ) -> PyResult<Vec<BoostPadRoute>> {
+        Ok(self.get_boost_pad_routes(car_index, options)?)
+    }
+
+    #[pyo3(name = "get_data_for_shot_with_target")]
+    fn py_get_data_for_shot_with_target(&self, target_index: usize) -> PyResult<AdvancedShotInfo> {
+        Ok(self.get_data_for_shot_with_target(target_index)?)
+    }
+
+    #[pyo3(name = "get_controls_for_shot")]
+    fn py_get_controls_for_shot(&mut self, target_index: usize) -> PyResult<ControllerState> {
+        Ok(self.get_controls_for_shot(target_index)?)
+    }
+
+    #[pyo3(name = "get_post_hit_prediction")]
+    fn py_get_post_hit_prediction(&self, target_index: usize, prediction_time: Option<f32>) -> PyResult<PostHitPrediction> {
+        Ok(self.get_post_hit_prediction(target_index, prediction_time)?)
+    }
+}
+
+fn get_may_shoot(
+    may_ground_shot: Option<bool>,
+    may_jump_shot: Option<bool>,
+    may_double_jump_shot: Option<bool>,
+    may_aerial_shot: Option<bool>,
+    only: Option<bool>,
+    may_ceiling_shot: Option<bool>,
+    may_dodge_shot: Option<bool>,
+) -> RlibResult<[bool; 6]> {
+    let only = only.unwrap_or(false);
+
+    let may_shoot = [
+        may_ground_shot.unwrap_or(!only),
+        may_jump_shot.unwrap_or(!only),
+        may_double_jump_shot.unwrap_or(!only),
+        may_aerial_shot.unwrap_or(!only),
+        may_ceiling_shot.unwrap_or(!only),
+        may_dodge_shot.unwrap_or(!only),
+    ];
+
+    if !may_shoot.iter().any(|&x| x) {
+        return Err(RlibError::NoShotSelected);
+    }
+
+    Ok(may_shoot)
+}
+
+/// Spawn ids are only useful for telling cars apart if every car has a different one,
+/// but some sources of game tick packets leave them all at 0
+fn has_unique_spawn_ids(spawn_ids: impl Iterator<Item = i32>) -> bool {
+    let mut seen = Vec::new();
+
+    for spawn_id in spawn_ids {
+        if spawn_id == 0 || seen.contains(&spawn_id) {
+            return false;
+        }
+
+        seen.push(spawn_id);
+    }
+
+    true
+}
+
+#[inline]
+fn is_ball_out_of_field(ball: &Ball, arena: Arena) -> bool {
+    arena
+        .goal_line()
+        .map_or(false, |goal_line| ball.location.y.abs() > goal_line + ball.collision_radius())
+}
+
+fn analyze_shot(
+    analyzer: &Analyzer,
+    balls: &Predictions,
+    target: &Target,
+    mutators: Mutators,
+    temporary: bool,
+    game_time: f32,
+    arena: Arena,
+) -> Option<(Shot, BasicShotInfo)> {
+    // with rank, every slice is checked and the best shot wins instead of the earliest one
+    if target.options.rank {
+        return rank_shots(analyzer, balls, target, mutators, game_time, arena)
+            .into_iter()
+            .next()
+            .map(|(shot, ranked_shot)| (shot, ranked_shot.shot));
+    }
+
+    for (i, ball) in balls[target.options.min_slice..target.options.max_slice].iter().enumerate() {
+        if target.destination.is_none() && is_ball_out_of_field(ball, arena) {
+            break;
+        }
+
+        let slice_num = target.options.min_slice + i;
+
+        if let Ok(found) = analyze_slice(analyzer, ball, slice_num, target, mutators, temporary, game_time) {
+            return Some(found);
+        }
+    }
+
+    None
+}
+
+/// Every viable shot in the target's range of slices, best first
+fn rank_shots(
+    analyzer: &Analyzer,
+    balls: &Predictions,
+    target: &Target,
+    mutators: Mutators,
+    game_time: f32,
+    arena: Arena,
+) -> Vec<(Shot, RankedShot)> {
+    let mut ranked_shots = Vec::new();
+
+    for (i, ball) in balls[target.options.min_slice..target.options.max_slice].iter().enumerate() {
+        if target.destination.is_none() && is_ball_out_of_field(ball, arena) {
+            break;
+        }
+
+        let slice_num = target.options.min_slice + i;
+
+        if let Ok((shot, basic_shot_info)) = analyze_slice(analyzer, ball, slice_num, target, mutators, false, game_time) {
+            let ranked_shot = rank_shot(
+                analyzer,
+                ball,
+                slice_num,
+                target,
+                &shot,
+                basic_shot_info,
+                mutators,
+                game_time,
+                arena,
+            );
+            ranked_shots.push((shot, ranked_shot));
+        }
+    }
+
+    ranked_shots.sort_by(|(_, a), (_, b)| b.score.total_cmp(&a.score));
+    ranked_shots
+}
+
+/// How much a second of waiting for the ball takes away from a shot's score
+const SCORE_TIME_WEIGHT: f32 = 0.1;
+/// How much being able to go twice as fast as needed adds to a shot's score
+const SCORE_MARGIN_WEIGHT: f32 = 0.5;
+/// How much using a full tank of boost takes away from a shot's score
+const SCORE_BOOST_WEIGHT: f32 = 0.5;
+
+/// Scores a viable shot on how soon it happens, how much room for error it has, how much boost it uses,
+/// and how well it sends the ball towards the target and the opponent's goal
+#[allow(clippy::too_many_arguments)]
+fn rank_shot(
+    analyzer: &Analyzer,
+    ball: &Ball,
+    slice_num: usize,
+    target: &Target,
+    shot: &Shot,
+    basic_shot_info: BasicShotInfo,
+    mutators: Mutators,
+    game_time: f32,
+    arena: Arena,
+) -> RankedShot {
+    let car = analyzer.car;
+    let time_to_contact = (ball.time - game_time).max(SIMULATION_DT);
+
+    let distance = match shot {
+        Shot::GroundBased(shot) => shot.distances.iter().sum(),
+        Shot::AirBased(shot) => car.location.distance(shot.final_target),
+        Shot::Ceiling(shot) => {
+            shot.ground.distances.iter().sum::<f32>() + shot.leave_location.distance(shot.air.final_target)
+        }
+    };
+    let required_speed = distance / time_to_contact;
+    let speed_margin = car.max_speed[slice_num] - required_speed;
+    let boost_cost = estimate_boost_cost(car, shot, required_speed, time_to_contact, analyzer.gravity, mutators);
+
+    // driving to a location doesn't touch the ball
+    let ball_velocity = if target.destination.is_some() {
+        ball.velocity
+    } else {
+        get_contact(car, shot, ball, time_to_contact).hit(ball, car)
+    };
+
+    let alignment = target.location.as_ref().map(|location| {
+        let middle = (location.left + location.right) / 2.;
+        flatten(ball_velocity)
+            .normalize_or_zero()
+            .dot(flatten(middle - ball.location).normalize_or_zero())
+    });
+
+    // blue attacks the orange goal, which is on the positive y side of the field
+    let attacking = if car.team == 0 { 1. } else { -1. };
+    let ball_speed_toward_goal = match arena.goal_line() {
+        Some(goal_line) => {
+            ball_velocity.dot((Vec3A::new(0., goal_line * attacking, 0.) - ball.location).normalize_or_zero())
+        }
+        None => ball_velocity.y * attacking,
+    };
+
+    let score = ball_speed_toward_goal / BALL_MAX_SPEED
+        + alignment.unwrap_or_default()
+        + (speed_margin / required_speed.max(1.)).clamp(-1., 1.) * SCORE_MARGIN_WEIGHT
+        - boost_cost / 100. * SCORE_BOOST_WEIGHT
+        - time_to_contact * SCORE_TIME_WEIGHT;
+
+    RankedShot {
+        shot: basic_shot_info,
+        slice_num,
+        time_to_contact,
+        speed_margin,
+        boost_cost,
+        alignment,
+        ball_speed_toward_goal,
+        score,
+    }
+}
+
+/// Estimates how much boost the car will use to take the shot
+fn estimate_boost_cost(
+    car: &Car,
+    shot: &Shot,
+    required_speed: f32,
+    time_remaining: f32,
+    gravity: Vec3A,
+    mutators: Mutators,
+) -> f32 {
+    if matches!(mutators.boost_amount, BoostAmount::Unlimited | BoostAmount::NoBoost) {
+        return 0.;
+    }
+
+    let boost_time = match shot {
+        // boosting is only needed to go faster than the car can drive without it
+        Shot::GroundBased(_) | Shot::Ceiling(_) => {
+            (required_speed - flatten(car.velocity).length().max(MAX_SPEED_NO_BOOST)).max(0.) / mutators.boost_accel
+        }
+        // the constant acceleration, on top of gravity, that takes the car to the target in time
+        Shot::AirBased(shot) => {
+            let displacement =
+                shot.final_target - car.location - car.velocity * time_remaining - gravity * 0.5 * time_remaining.powi(2);
+            let acceleration = 2. * displacement.length() / time_remaining.powi(2);
+
+            time_remaining * (acceleration / mutators.boost_accel).min(1.)
+        }
+    };
+
+    (boost_time * BOOST_CONSUMPTION).min(f32::from(car.boost))
+}
+
+fn analyze_slice(
+    analyzer: &Analyzer,
+    ball: &Ball,
+    slice_num: usize,
+    target: &Target,
+    mutators: Mutators,
+    temporary: bool,
+    game_time: f32,
+) -> Result<(Shot, BasicShotInfo), ShotRejection> {
+    let max_time_remaining = ball.time - game_time;
+
+    if let Some(destination) = &target.destination {
+        let target_info = find_ground_path(analyzer, max_time_remaining, mutators, |turning| {
+            analyzer.destination(destination, max_time_remaining, slice_num, turning)
+        })?;
+
+        return Ok(found_destination(ball.time, destination, &target_info, temporary));
+    }
+
+    let shot_type = analyzer.get_shot_type(ball.location, max_time_remaining)?;
+
+    if let Some(target_location) = &target.location {
+        let post_info = PostCorrection::new(
+            ball.location,
+            ball.collision_radius(),
+            target_location.left,
+            target_location.right,
+        );
+
+        if !post_info.fits {
+            return Err(ShotRejection::PostDoesntFit);
+        }
+
+        let shot_vector = post_info.get_shot_vector_target(analyzer.car.landing_location, ball.location);
+
+        if matches!(shot_type, ShotType::Aerial | ShotType::Ceiling) {
+            let ball_edge = ball.location - flatten(shot_vector) * ball.radius();
+            let target_location = ball_edge
+                - Vec3A::new(0., 0., shot_vector.z) * (analyzer.car.hitbox_offset.x + analyzer.car.hitbox.length) / 2.;
+
+            return analyze_aerial(
+                analyzer,
+                ball,
+                slice_num,
+                shot_type,
+                target_location,
+                shot_vector,
+                Some(ball.location),
+                mutators,
+                temporary,
+                game_time,
+            );
+        }
+
+        analyze_ground(
+            analyzer,
+            ball,
+            slice_num,
+            shot_type,
+            Some(shot_vector),
+            mutators,
+            temporary,
+            game_time,
+        )
+    } else if matches!(shot_type, ShotType::Aerial | ShotType::Ceiling) {
+        let ball_edge = ball.location - flatten(ball.location - analyzer.car.location).normalize_or_zero() * ball.radius();
+        let shot_vector = (ball_edge - analyzer.car.location).normalize_or_zero();
+        let target_location = ball_edge - shot_vector * (analyzer.car.hitbox_offset.x + analyzer.car.hitbox.length) / 2.;
+
+        analyze_aerial(
+            analyzer,
+            ball,
+            slice_num,
+            shot_type,
+            target_location,
+            shot_vector,
+            None,
+            mutators,
+            temporary,
+            game_time,
+        )
+    } else {
+        analyze_ground(analyzer, ball, slice_num, shot_type, None, mutators, temporary, game_time)
+    }
+}
+
+/// Finds a path with `find` that steers through its turns at the car's full speed,
+/// or one with tighter turns that the car slows down or powerslides through if that's the only way to get there in time
+fn find_ground_path(
+    analyzer: &Analyzer,
+    max_time_remaining: f32,
+    mutators: Mutators,
+    find: impl Fn(Turning) -> Result<GroundTargetInfo, ShotRejection>,
+) -> Result<GroundTargetInfo, ShotRejection> {
+    let reach = |turning| -> Result<GroundTargetInfo, ShotRejection> {
+        let target_info = find(turning)?;
+        target_info.can_reach(analyzer.car, max_time_remaining, mutators)?;
+
+        if !target_info.is_clear(analyzer.car, &analyzer.obstacles, max_time_remaining, analyzer.gravity) {
+            return Err(ShotRejection::PathBlocked);
+        }
+
+        Ok(target_info)
+    };
+
+    // report why the normal path didn't work
+    reach(Turning::Steer).or_else(|rejection| {
+        // slowing down or sliding while reversing is too unpredictable to plan around
+        let reach_forwards = |turning| reach(turning).ok().filter(|target_info| target_info.is_forwards);
+
+        if let Some(target_info) = reach_forwards(Turning::QuickSteer) {
+            return Ok(target_info);
+        }
+
+        // powersliding only loses speed if the car isn't already going fast
+        if flatten(analyzer.car.landing_velocity).length() <= MIN_POWERSLIDE_SPEED {
+            return Err(rejection);
+        }
+
+        reach_forwards(Turning::Powerslide).ok_or(rejection)
+    })
+}
+
+/// Dodge shots fall back to the jump shot that they upgrade if the dodge isn't possible
+#[allow(clippy::too_many_arguments)]
+fn analyze_ground(
+    analyzer: &Analyzer,
+    ball: &Ball,
+    slice_num: usize,
+    shot_type: ShotType,
+    shot_vector: Option<Vec3A>,
+    mutators: Mutators,
+    temporary: bool,
+    game_time: f32,
+) -> Result<(Shot, BasicShotInfo), ShotRejection> {
+    let max_time_remaining = ball.time - game_time;
+
+    let analyze = |shot_type| {
+        let target_info = find_ground_path(analyzer, max_time_remaining, mutators, |turning| match shot_vector {
+            Some(shot_vector) => analyzer.target(ball, shot_vector, max_time_remaining, slice_num, shot_type, turning),
+            None => analyzer.no_target(ball, max_time_remaining, slice_num, shot_type, turning),
+        })?;
+
+        Ok(found_ground_shot(ball, &target_info, temporary))
+    };
+
+    match analyze(shot_type) {
+        // report why the dodge didn't work
+        Err(rejection) if shot_type == ShotType::Dodge && analyzer.may_jump_instead_of_dodge(ball.location) => {
+            analyze(ShotType::Jump).map_err(|_| rejection)
+        }
+        result => result,
+    }
+}
+
+/// Aerials can fall back to dropping off of the ceiling if they aren't viable from where the car is
+#[allow(clippy::too_many_arguments)]
+fn analyze_aerial(
+    analyzer: &Analyzer,
+    ball: &Ball,
+    slice_num: usize,
+    shot_type: ShotType,
+    target_location: Vec3A,
+    shot_vector: Vec3A,
+    check_target_angle: Option<Vec3A>,
+    mutators: Mutators,
+    temporary: bool,
+    game_time: f32,
+) -> Result<(Shot, BasicShotInfo), ShotRejection> {
+    let max_time_remaining = ball.time - game_time;
+
+    let rejection = if shot_type == ShotType::Aerial {
+        match analyzer.aerial_shot(mutators, target_location, shot_vector, max_time_remaining, check_target_angle) {
+            Ok(target_info) => return Ok(found_air_shot(ball, &target_info, temporary)),
+            Err(rejection) => Some(rejection),
+        }
+    } else {
+        None
+    };
+
+    // ceiling shots are opt-in, and finding a path up the wall isn't cheap
+    if !analyzer.may[4] {
+        return Err(rejection.unwrap_or(ShotRejection::ShotTypeUnavailable));
+    }
+
+    match analyzer.ceiling_shot(
+        mutators,
+        target_location,
+        shot_vector,
+        max_time_remaining,
+        slice_num,
+        check_target_angle,
+    ) {
+        Ok(target_info) => Ok(found_ceiling_shot(ball, &target_info, game_time, temporary)),
+        // report why the aerial didn't work, if one was tried
+        Err(ceiling_rejection) => Err(rejection.unwrap_or(ceiling_rejection)),
+    }
+}
+
+/// Where the car will be when it touches the ball,
+/// if it closes in at either its current speed or the average speed that the shot needs, whichever is faster
+fn get_contact(car: &Car, shot: &Shot, ball: &Ball, time_remaining: f32) -> Contact {
+    let time_remaining = time_remaining.max(SIMULATION_DT);
+
+    let aerial_contact = |shot: &AirBasedShot| {
+        let velocity = (shot.final_target - car.location) / time_remaining;
+        Contact::new(ball, shot.shot_vector, rotmat_from(shot.shot_vector, Vec3A::Z), velocity, car)
+    };
+
+    match shot {
+        Shot::GroundBased(shot) => {
+            let (shot_vector, up) = shot.wall.map_or((shot.direction, Vec3A::Z), |wall| {
+                (wall.fold_vector(shot.direction), -wall.outward())
+            });
+            let forward = (shot_vector - up * shot_vector.dot(up)).normalize_or_zero();
+
+            let average_speed = car.location.distance(ball.location) / time_remaining;
+            let mut speed = average_speed.max(car.velocity.length()).min(MAX_SPEED);
+
+            if shot.dodge.is_some() {
+                speed = (speed + DODGE_IMPULSE).min(MAX_SPEED);
+            }
+
+            Contact::new(ball, shot_vector, rotmat_from(forward, up), forward * speed, car)
+        }
+        Shot::AirBased(shot) => aerial_contact(shot),
+        Shot::Ceiling(shot) => aerial_contact(&shot.air),
+    }
+}
+
+#[inline]
+fn found_ceiling_shot(
+    ball: &Ball,
+    target_info: &CeilingTargetInfo,
+    game_time: f32,
+    temporary: bool,
+) -> (Shot, BasicShotInfo) {
+    let basic_shot_info = target_info.get_basic_shot_info(ball.time);
+    let found_shot = if temporary {
+        CeilingShot::default()
+    } else {
+        CeilingShot::new(ball, target_info, game_time)
+    };
+
+    (found_shot.into(), basic_shot_info)
+}
+
+#[inline]
+fn found_air_shot(ball: &Ball, target_info: &AerialTargetInfo, temporary: bool) -> (Shot, BasicShotInfo) {
+    let basic_shot_info = target_info.get_basic_shot_info(ball.time);
+    let found_shot = if temporary {
+        AirBasedShot::default()
+    } else {
+        AirBasedShot::new(ball, target_info)
+    };
+
+    (found_shot.into(), basic_shot_info)
+}
+
+#[inline]
+fn found_ground_shot(ball: &Ball, target_info: &GroundTargetInfo, temporary: bool) -> (Shot, BasicShotInfo) {
+    let basic_shot_info = target_info.get_basic_shot_info(ball.time);
+    let found_shot = if temporary {
+        GroundBasedShot::default()
+    } else {
+        GroundBasedShot::new(ball, target_info)
+    };
+
+    (found_shot.into(), basic_shot_info)
+}
+
+#[inline]
+fn found_destination(
+    time: f32,
+    destination: &Destination,
+    target_info: &GroundTargetInfo,
+    temporary: bool,
+) -> (Shot, BasicShotInfo) {
+    let basic_shot_info = target_info.get_basic_shot_info(time);
+    let found_shot = if temporary {
+        GroundBasedShot::default()
+    } else {
+        GroundBasedShot::new_at(time, destination.location, target_info)
+    };
+
+    (found_shot.into(), basic_shot_info)
+}
+
+#[cfg(test)]
+mod tests {
+    use std::f32::consts::FRAC_PI_2;
+
+    use crate::{
+        car::{OCTANE_HITBOX, OCTANE_HITBOX_OFFSET},
+        error::{RlibError, RlibResult},
+        ground::Turning,
+        pytypes::{
+            BasicShotInfo, GameBall, GameBoostPad, GameCar, GameCollisionShape, GameFieldInfo, GameInfo, GamePacket,
+            GamePhysics, GameRot, GameSphere, GameVec, ShotRejection, ShotType, TargetOptions,
+        },
+        shot::Shot,
+        world::World,
+    };
+
+    /// Searches for a shot at the target with every option left at its default
+    fn find_shot(world: &mut World, target_index: usize) -> RlibResult<BasicShotInfo> {
+        world.get_shot_with_target(target_index, None, None, None, None, None, None, None, None)
+    }
+
+    fn game_vec([x, y, z]: [f32; 3]) -> GameVec {
+        GameVec { x, y, z }
+    }
+
+    fn game_car(spawn_id: i32, team: u8, location: [f32; 3], yaw: f32) -> GameCar {
+        GameCar {
+            physics: GamePhysics {
+                location: game_vec(location),
+                rotation: GameRot {
+                    pitch: 0.,
+                    yaw,
+                    roll: 0.,
+                },
+                ..Default::default()
+            },
+            hitbox: OCTANE_HITBOX,
+            hitbox_offset: game_vec(OCTANE_HITBOX_OFFSET.to_array()),
+            spawn_id,
+            boost: 50,
+            team,
+            has_wheel_contact: true,
+            ..Default::default()
+        }
+    }
+
+    fn packet(ball_location: [f32; 3], ball_velocity: [f32; 3], cars: Vec<GameCar>) -> GamePacket {
+        GamePacket {
+            game_info: GameInfo {
+                seconds_elapsed: 10.,
+                game_time_remaining: 290.,
+                world_gravity_z: -650.,
+                ..Default::default()
+            },
+            game_ball: GameBall {
+                physics: GamePhysics {
+                    location: game_vec(ball_location),
+                    velocity: game_vec(ball_velocity),
+                    ..Default::default()
+                },
+                collision_shape: GameCollisionShape {
+                    shape_type: 1,
+                    sphere: GameSphere { diameter: 182.5 },
+                    ..Default::default()
+                },
+            },
+            num_cars: cars.len(),
+            game_cars: cars,
+            ..Default::default()
+        }
+    }
+
+    /// A standard arena with the ball resting in the middle, and a blue car in front of it facing the orange goal
+    fn kickoff_world() -> World {
+        let mut world = World::new();
+        world.load_standard();
+        world
+            .tick(
+                packet([0., 0., 92.75], [0.; 3], vec![game_car(1, 0, [0., -2500., 17.], FRAC_PI_2)]),
+                None,
+            )
+            .unwrap();
+        world
+    }
+
+    /// Targets the goal that the first car attacks
+    fn new_goal_target(world: &mut World, options: Option<TargetOptions>) -> usize {
+        let ((lx, ly, lz), (rx, ry, rz)) = world.get_opponent_goal(0).unwrap();
+        world.new_target([lx, ly, lz], [rx, ry, rz], 0, options).unwrap()
+    }
+
+    #[test]
+    pub fn shot_diagnostics() {
+        let mut world = kickoff_world();
+        let target_index = new_goal_target(&mut world, None);
+
+        let diagnostics = world
+            .get_shot_diagnostics(target_index, None, None, None, None, None, None, None)
+            .unwrap();
+        let (found, rejected) = diagnostics.split_last().unwrap();
+
+        // the car is too far away to get to the ball right away, and the search stops at the first viable slice
+        assert!(!rejected.is_empty());
+        assert!(rejected.iter().all(|diagnostic| diagnostic.rejection.is_some()));
+        assert!(found.rejection.is_none());
+
+        let shot = find_shot(&mut world, target_index).unwrap();
+        assert!(shot.found);
+        assert_eq!(shot.time, found.time);
+        assert_eq!(shot.shot_type, found.shot_type);
+    }
+
+    #[test]
+    pub fn boost_pad_layout_from_field_info() {
+        let packet = || packet([0., 0., 92.75], [0.; 3], vec![game_car(1, 0, [0., -2500., 17.], FRAC_PI_2)]);
+
+        let mut world = World::new();
+        world.load_standard_throwback();
+        world.tick(packet(), None).unwrap();
+
+        // throwback's layout isn't built in
+        assert_eq!(world.get_boost_pad_routes(0, None).unwrap_err(), RlibError::NoBoostPads);
+
+        let field_info = GameFieldInfo {
+            boost_pads: vec![GameBoostPad {
+                location: game_vec([0., -2000., 70.]),
+                is_full_boost: false,
+            }],
+            num_boosts: 1,
+        };
+
+        // the layout is kept for the next time the arena is loaded
+        world.set_field_info(field_info);
+        world.load_standard_throwback();
+        world.tick(packet(), None).unwrap();
+
+        let routes = world.get_boost_pad_routes(0, None).unwrap();
+        assert_eq!(routes.len(), 1);
+        assert_eq!(routes[0].location, (0., -2000., 70.));
+    }
+
+    #[test]
+    pub fn location_target_arrival_time() {
+        let mut world = kickoff_world();
+        let location = [0., -1000., 17.];
+
+        let target_index = world
+            .new_location_target(0, location, None, Some(world.game_time + 2.), None)
+            .unwrap();
+        let shot = find_shot(&mut world, target_index).unwrap();
+        assert!(shot.found);
+        assert!((shot.time - (world.game_time + 2.)).abs() < 0.001);
+
+        // the arrival time overrides the target's range of slices
+        let options = TargetOptions {
+            max_slice: Some(0),
+            ..Default::default()
+        };
+        assert!(world
+            .new_location_target(0, location, None, Some(world.game_time + 1.), Some(options))
+            .is_ok());
+
+        assert_eq!(
+            world
+                .new_location_target(0, location, None, Some(world.game_time - 1.), None)
+                .unwrap_err(),
+            RlibError::NoTimeRemaining
+        );
+        assert_eq!(
+            world
+                .new_location_target(0, location, None, Some(world.game_time), None)
+                .unwrap_err(),
+            RlibError::NoTimeRemaining
+        );
+        assert_eq!(
+            world
+                .new_location_target(0, location, None, Some(world.game_time + 60.), None)
+                .unwrap_err(),
+            RlibError::NoSlices
+        );
+    }
+
+    #[test]
+    pub fn dodge_shots_are_opt_in() {
+        let mut world = kickoff_world();
+
+        // without ground shots, a ball on the ground can't be hit unless the target asked for dodge shots
+        let target_index = new_goal_target(&mut world, None);
+        let shot = world
+            .get_shot_with_target(target_index, None, Some(false), None, None, None, None, None, None)
+            .unwrap();
+        assert_ne!(shot.shot_type, Some(ShotType::Dodge));
+
+        let options = TargetOptions {
+            dodge_shots: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let shot = world
+            .get_shot_with_target(target_index, None, Some(false), None, None, None, None, None, None)
+            .unwrap();
+        assert_eq!(shot.shot_type, Some(ShotType::Dodge));
+
+        // asking for only dodge shots on a target that didn't opt in is asking for nothing
+        let target_index = new_goal_target(&mut world, None);
+        assert_eq!(
+            world
+                .get_shot_with_target(target_index, None, None, None, None, None, Some(true), None, Some(true))
+                .unwrap_err(),
+            RlibError::NoShotSelected
+        );
+    }
+
+    #[test]
+    pub fn ceiling_shots_are_opt_in() {
+        let mut world = kickoff_world();
+
+        // asking for only ceiling shots on a target that didn't opt in is asking for nothing
+        let target_index = new_goal_target(&mut world, None);
+        assert_eq!(
+            world
+                .get_shot_with_target(target_index, None, None, None, None, None, Some(true), Some(true), None)
+                .unwrap_err(),
+            RlibError::NoShotSelected
+        );
+
+        let options = TargetOptions {
+            ceiling_shots: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let diagnostics = world
+            .get_shot_diagnostics(target_index, None, None, None, None, Some(true), Some(true), None)
+            .unwrap();
+        assert!(!diagnostics.is_empty());
+
+        // the rest of the shots are searched for the same way either way
+        let default_index = new_goal_target(&mut world, None);
+        let default_shot = find_shot(&mut world, default_index).unwrap();
+        let ceiling_shot = find_shot(&mut world, target_index).unwrap();
+        assert_eq!(default_shot.time, ceiling_shot.time);
+        assert_eq!(default_shot.shot_type, ceiling_shot.shot_type);
+    }
+
+    #[test]
+    pub fn all_and_rank() {
+        let mut world = kickoff_world();
+
+        let target_index = new_goal_target(&mut world, None);
+        let earliest = find_shot(&mut world, target_index).unwrap();
+        assert!(earliest.found);
+
+        // all searches every slice, but still returns the earliest shot
+        let options = TargetOptions {
+            all: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let shot = find_shot(&mut world, target_index).unwrap();
+        assert_eq!(shot.time, earliest.time);
+
+        // rank returns the best shot out of every slice instead
+        let options = TargetOptions {
+            rank: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let ranked_shots = world
+            .get_ranked_shots(target_index, None, None, None, None, None, None, None, None)
+            .unwrap();
+        assert!(ranked_shots.len() > 1);
+        assert!(ranked_shots.windows(2).all(|pair| pair[0].score >= pair[1].score));
+        assert!(ranked_shots.iter().all(|ranked_shot| ranked_shot.shot.time >= earliest.time));
+
+        let shot = find_shot(&mut world, target_index).unwrap();
+        assert_eq!(shot.time, ranked_shots[0].shot.time);
+    }
+
+    #[test]
+    pub fn controls_for_shot() {
+        let mut world = kickoff_world();
+
+        let target_index = new_goal_target(&mut world, None);
+        assert_eq!(world.get_controls_for_shot(target_index).unwrap_err(), RlibError::NoShot);
+
+        find_shot(&mut world, target_index).unwrap();
+
+        // the ball is straight ahead, so the car just drives at it
+        let controls = world.get_controls_for_shot(target_index).unwrap();
+        assert!(controls.throttle > 0.);
+        assert!(controls.steer.abs() < 0.1);
+        assert!(!controls.jump);
+        assert!(!controls.handbrake);
+
+        // the field is left-handed, so +x is on the left of a car that's facing +y, and positive steer turns right
+        let [left, right] = [1500., -1500.].map(|x| {
+            let target_index = world.new_location_target(0, [x, -1000., 17.], None, None, None).unwrap();
+            find_shot(&mut world, target_index).unwrap();
+            world.get_controls_for_shot(target_index).unwrap().steer
+        });
+        assert!(left < -0.1);
+        assert!(right > 0.1);
+        assert!((left + right).abs() < 0.01);
+    }
+
+    #[test]
+    pub fn powerslide_fallback() {
+        // the ball is off to the side of a car that's driving towards the orange goal
+        let turning = |speed: f32| {
+            let mut car = game_car(1, 0, [0., -2500., 17.], FRAC_PI_2);
+            car.physics.velocity = game_vec([0., speed, 0.]);
+
+            let mut world = World::new();
+            world.load_standard();
+            world.tick(packet([700., -1700., 92.75], [0.; 3], vec![car]), None).unwrap();
+
+            let target_index = world.new_any_target(0, None).unwrap();
+            let shot = find_shot(&mut world, target_index).unwrap();
+            assert!(shot.found);
+
+            let controls = world.get_controls_for_shot(target_index).unwrap();
+            match &world.get_target(target_index).unwrap().shot {
+                Some(Shot::GroundBased(shot)) => (shot.turning, controls.handbrake),
+                _ => unreachable!(),
+            }
+        };
+
+        // a slow car can slow down even more to make the turn
+        assert_eq!(turning(1000.), (Turning::QuickSteer, false));
+
+        // but a fast one has to slide around it
+        assert_eq!(turning(2200.), (Turning::Powerslide, true));
+    }
+
+    #[test]
+    pub fn avoid_opponents() {
+        let mut world = World::new();
+        world.load_standard();
+
+        // an orange car is parked in between the blue car and the ball
+        let cars = vec![
+            game_car(1, 0, [0., -2500., 17.], FRAC_PI_2),
+            game_car(2, 1, [0., -1250., 17.], -FRAC_PI_2),
+        ];
+        world.tick(packet([0., 0., 92.75], [0.; 3], cars), None).unwrap();
+
+        let target_index = new_goal_target(&mut world, None);
+        let shot = find_shot(&mut world, target_index).unwrap();
+        assert!(shot.found);
+
+        // avoiding teammates doesn't avoid opponents
+        let options = TargetOptions {
+            avoid_teammates: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let teammates_shot = find_shot(&mut world, target_index).unwrap();
+        assert_eq!(teammates_shot.time, shot.time);
+
+        // every path to the ball goes through the orange car
+        let options = TargetOptions {
+            avoid_opponents: Some(true),
+            ..Default::default()
+        };
+        let target_index = new_goal_target(&mut world, Some(options));
+        let diagnostics = world
+            .get_shot_diagnostics(target_index, None, None, None, None, None, None, None)
+            .unwrap();
+        assert!(diagnostics
+            .iter()
+            .any(|diagnostic| diagnostic.rejection == Some(ShotRejection::PathBlocked)));
+
+        let avoiding_shot = find_shot(&mut world, target_index).unwrap();
+        assert!(!avoiding_shot.found);
+    }
+
+    #[test]
+    pub fn all_car_intercepts() {
+        let mut world = World::new();
+        world.load_standard();
+        assert_eq!(
+            world
+                .get_all_car_intercepts(None, None, None, None, None, None, None, None)
+                .unwrap_err(),
+            RlibError::NoSlices
+        );
+
+        // the orange car is much further from the ball than the blue car
+        let cars = vec![
+            game_car(1, 0, [0., -1500., 17.], FRAC_PI_2),
+            game_car(2, 1, [0., 4500., 17.], -FRAC_PI_2),
+        ];
+        world.tick(packet([0., 0., 92.75], [0.; 3], cars), None).unwrap();
+
+        let intercepts = world
+            .get_all_car_intercepts(None, None, None, None, None, None, None, None)
+            .unwrap();
+        assert_eq!(intercepts.len(), 2);
+        assert_eq!((intercepts[0].car_index, intercepts[0].team), (0, 0));
+        assert_eq!((intercepts[1].car_index, intercepts[1].team), (1, 1));
+        assert!(intercepts[0].time.unwrap() < intercepts[1].time.unwrap());
+
+        // the same search as a target from new_any_target
+        for intercept in &intercepts {
+            let target_index = world.new_any_target(intercept.car_index, None).unwrap();
+            let shot = find_shot(&mut world, target_index).unwrap();
+            assert_eq!(intercept.time, Some(shot.time));
+            assert_eq!(intercept.shot_type, shot.shot_type);
+            assert_eq!(intercept.slice_num, Some(((shot.time - 10.) * 120.).round() as usize - 1));
+        }
+
+        // nothing can be hit in half a second
+        let options = TargetOptions {
+            max_slice: Some(60),
+            ..Default::default()
+        };
+        let intercepts = world
+            .get_all_car_intercepts(Some(options), None, None, None, None, None, None, None)
+            .unwrap();
+        assert!(intercepts
+            .iter()
+            .all(|intercept| intercept.slice_num.is_none() && intercept.time.is_none()));
+    }
+
+    #[test]
+    pub fn targets_follow_their_cars() {
+        let blue = |spawn_id| game_car(spawn_id, 0, [0., -2500., 17.], FRAC_PI_2);
+        let orange = |spawn_id| game_car(spawn_id, 1, [0., 2500., 17.], -FRAC_PI_2);
+
+        // a confirmed target for the orange car, which is the second car
+        let confirmed_target = |spawn_ids: [i32; 2]| {
+            let mut world = World::new();
+            world.load_standard();
+            world
+                .tick(
+                    packet([0., 0., 92.75], [0.; 3], vec![blue(spawn_ids[0]), orange(spawn_ids[1])]),
+                    None,
+                )
+                .unwrap();
+
+            let target_index = world.new_any_target(1, None).unwrap();
+            find_shot(&mut world, target_index).unwrap();
+            world.confirm_target(target_index).unwrap();
+
+            (world, target_index)
+        };
+        let car_index = |world: &World, target_index| world.get_target(target_index).unwrap().car_index;
+
+        // the target follows the orange car when it's moved to the front
+        let (mut world, target_index) = confirmed_target([1, 2]);
+        world
+            .tick(packet([0., 0., 92.75], [0.; 3], vec![orange(2), blue(1)]), None)
+            .unwrap();
+        assert_eq!(car_index(&world, target_index), Some(0));
+        assert!(find_shot(&mut world, target_index).is_ok());
+
+        // and the target's car is gone when the orange car leaves
+        world.tick(packet([0., 0., 92.75], [0.; 3], vec![blue(1)]), None).unwrap();
+        assert_eq!(car_index(&world, target_index), None);
+        assert_eq!(find_shot(&mut world, target_index).unwrap_err(), RlibError::CarGone);
+
+        // spawn ids that are missing or shared can't tell the cars apart, so the target stays with the second car
+        for spawn_ids in [[0, 0], [3, 3]] {
+            let (mut world, target_index) = confirmed_target(spawn_ids);
+            world
+                .tick(
+                    packet([0., 0., 92.75], [0.; 3], vec![orange(spawn_ids[1]), blue(spawn_ids[0])]),
+                    None,
+                )
+                .unwrap();
+            assert_eq!(car_index(&world, target_index), Some(1));
+
+            world
+                .tick(packet([0., 0., 92.75], [0.; 3], vec![blue(spawn_ids[0])]), None)
+                .unwrap();
+            assert_eq!(car_index(&world, target_index), None);
+        }
+    }
+
+    #[test]
+    pub fn game_state_transitions() {
+        // (state, is_round_active, is_kickoff_pause, is_match_ended, if shots are found)
+        let states = [
+            ("kickoff countdown", false, true, false, false),
+            ("kickoff", true, true, false, true),
+            ("goal replay", false, false, false, false),
+            ("match ended", true, false, true, false),
+        ];
+
+        for (name, is_round_active, is_kickoff_pause, is_match_ended, is_playing) in states {
+            let mut world = World::new();
+            world.load_standard();
+
+            let mut packet = packet([0., 0., 92.75], [0.; 3], vec![game_car(1, 0, [0., -2500., 17.], FRAC_PI_2)]);
+            packet.game_info.is_round_active = is_round_active;
+            packet.game_info.is_kickoff_pause = is_kickoff_pause;
+            packet.game_info.is_match_ended = is_match_ended;
+            world.tick(packet, None).unwrap();
+
+            let game_state = world.get_game_state();
+            assert_eq!(game_state.is_round_active, is_round_active, "{name}");
+            assert_eq!(game_state.is_kickoff_pause, is_kickoff_pause, "{name}");
+            assert_eq!(game_state.is_match_ended, is_match_ended, "{name}");
+            assert_eq!(game_state.is_playing(), is_playing, "{name}");
+
+            let target_index = new_goal_target(&mut world, None);
+            let shot = find_shot(&mut world, target_index).unwrap();
+            assert_eq!(shot.found, is_playing, "{name}");
+
+            // the refusal can be turned off
+            let options = TargetOptions {
+                search_while_stopped: Some(true),
+                ..Default::default()
+            };
+            let target_index = new_goal_target(&mut world, Some(options));
+            let shot = find_shot(&mut world, target_index).unwrap();
+            assert!(shot.found, "{name}");
+
+            // cars can always get into position
+            let target_index = world.new_location_target(0, [0., -1000., 17.], None, None, None).unwrap();
+            let shot = find_shot(&mut world, target_index).unwrap();
+            assert!(shot.found, "{name}");
+        }
+    }
+}
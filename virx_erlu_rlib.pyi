@@ -30,15 +30,26 @@ def load_standard_throwback() -> None:
 
 try:
     from rlbot.messages.flat.MutatorSettings import MutatorSettings
-    from rlbot.utils.structures.game_data_struct import GameTickPacket
+    from rlbot.utils.structures.game_data_struct import FieldInfoPacket, GameTickPacket
 except ImportError:
     pass
 
 
+def set_field_info(field_info: FieldInfoPacket) -> None:
+    """
+    Parses the boost pad layout from RLBot
+
+    Required for hoops and throwback, optional for standard
+    Can be called before or after loading the arena, and the layout is kept for loading hoops or throwback later
+    """
+
+
 def tick(packet: GameTickPacket, prediction_time: float=6.) -> None:
     """
     Parses the game tick packet from RLBot
 
+    If the boost pad layout is known, this also updates which boost pads are active
+
     prediction_time: The number of seconds into the future to generate the ball prediction struct
     """
 
@@ -54,6 +65,9 @@ class ShotType(Enum):
     Jump: int = 1
     DoubleJump: int = 2
     Aerial: int = 3
+    Wall: int = 4
+    Ceiling: int = 5
+    Dodge: int = 6
 
 
 class TargetOptions:
@@ -62,8 +76,15 @@ class TargetOptions:
     use_absolute_max_values: Optional[bool]
     all: Optional[bool]
     forwards_only: Optional[bool]
-
-    def __init__(self, min_slice: Optional[int]=None, max_slice: Optional[int]=None, use_absolute_max_values: Optional[bool]=None, all: Optional[bool]=None, fowards_only: Optional[bool]=None) -> TargetOptions: ...
+    simulate_aerials: Optional[bool]
+    avoid_teammates: Optional[bool]
+    avoid_opponents: Optional[bool]
+    dodge_shots: Optional[bool]
+    rank: Optional[bool]
+    search_while_stopped: Optional[bool]
+    ceiling_shots: Optional[bool]
+
+    def __init__(self, min_slice: Optional[int]=None, max_slice: Optional[int]=None, use_absolute_max_values: Optional[bool]=None, all: Optional[bool]=None, fowards_only: Optional[bool]=None, simulate_aerials: Optional[bool]=None, avoid_teammates: Optional[bool]=None, avoid_opponents: Optional[bool]=None, dodge_shots: Optional[bool]=None, rank: Optional[bool]=None, search_while_stopped: Optional[bool]=None, ceiling_shots: Optional[bool]=None) -> TargetOptions: ...
     def __str__(self) -> str: ...
     def __repr__(self) -> str: ...
 
@@ -95,11 +116,52 @@ def get_num_ball_slices() -> int:
     """
 
 
+class GameState:
+    game_time_remaining: float
+    is_overtime: bool
+    is_round_active: bool
+    is_kickoff_pause: bool
+    is_match_ended: bool
+
+    def __str__(self) -> str: ...
+    def __repr__(self) -> str: ...
+
+
+def get_game_state() -> GameState:
+    """
+    Gets the state of the match from the last game tick packet
+
+    No shots are found while the round isn't active (kickoff countdowns and goal replays) or after the match has ended,
+    unless the target's options have search_while_stopped=True
+    Location targets are always searched
+    """
+
+
+def get_own_goal(car_index: int) -> tuple[tuple[float, float, float], tuple[float, float, float]]:
+    """
+    Gets the left and right posts of the goal that the car defends, halfway up the goal
+
+    The posts are in the same order that new_target takes them, from the point of view of a car shooting into the goal
+    Raises a ValueError on hoops and dropshot, which don't have goals with posts
+    """
+
+
+def get_opponent_goal(car_index: int) -> tuple[tuple[float, float, float], tuple[float, float, float]]:
+    """
+    Gets the left and right posts of the goal that the car attacks, halfway up the goal
+
+    new_target(*get_opponent_goal(car_index), car_index) creates a target for shooting at the opponent's goal
+    Raises a ValueError on hoops and dropshot, which don't have goals with posts
+    """
+
+
 def new_target(left_target: tuple[float, float, float], right_target: tuple[float, float, float], car_index: int, options: Optional[TargetOptions]=None) -> int:
     """
     Creates a new target and returns the target's I.D.
 
     Targets get automatically deleted upon calling tick() if it hasn't been confirmed.
+    Confirmed targets follow their car by its spawn id if the cars are reordered, and raise a LookupError once the car leaves the game.
+    If any spawn id is 0 or shared by two cars, the cars are matched up by their index for that tick instead.
     """
 
 
@@ -111,6 +173,19 @@ def new_any_target(car_index: int, options: Optional[TargetOptions]=None) -> int
     """
 
 
+def new_location_target(car_index: int, location: tuple[float, float, float], arrival_yaw: Optional[float]=None, arrival_time: Optional[float]=None, options: Optional[TargetOptions]=None) -> int:
+    """
+    Creates a new target for driving to a point on the field instead of hitting the ball, and returns the target's I.D.
+
+    arrival_yaw: The direction the car should be facing when it arrives, defaults to whichever direction is fastest
+    arrival_time: The game time that the car should arrive at, defaults to as soon as possible
+
+    Raises a ValueError if arrival_time has already passed, or is past the end of the ball prediction
+    Use get_shot_with_target() to get the arrival time and get_data_for_shot_with_target() to follow the path, just like with a shot.
+    Targets get automatically deleted upon calling tick() if it hasn't been confirmed.
+    """
+
+
 def confirm_target(target_id: int) -> None:
     """
     Confirms a target so it isn't deleted upon calling tick()
@@ -148,7 +223,7 @@ class BasicShotInfo:
     def __str__(self) -> str: ...
 
 
-def get_shot_with_target(target_id: int, temporary: bool=False, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False) -> BasicShotInfo:
+def get_shot_with_target(target_id: int, temporary: bool=False, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> BasicShotInfo:
     """
     Searches the ball prediction struct for a shot
 
@@ -158,6 +233,128 @@ def get_shot_with_target(target_id: int, temporary: bool=False, may_ground_shot:
     may_double_jump_shot: Setting this to True will enable searching for double jump shots, default is the opposite of only
     may_aerial_shot: Setting this to True will enable searching for aerial shots, default is the opposite of only
     only: Default False, set to True if you only want to search for the specified shot(s)
+    may_ceiling_shot: Setting this to True will enable searching for shots that drop off of the ceiling, default is the opposite of only
+        This only applies to targets created with TargetOptions(ceiling_shots=True), because ceiling shots are off by default
+    may_dodge_shot: Setting this to True will enable searching for shots that dodge into the ball, which are tried before jump shots, default is the opposite of only
+        This only applies to targets created with TargetOptions(dodge_shots=True), because dodge shots are off by default
+
+    may_ceiling_shot and may_dodge_shot come after only so that calls that pass only by position keep working
+    """
+
+
+class ShotRejection(Enum):
+    BallOutOfField: int = 0
+    ShotTypeUnavailable: int = 1
+    PostDoesntFit: int = 2
+    TooFar: int = 3
+    TooClose: int = 4
+    NotEnoughJumpTime: int = 5
+    ExitOutOfField: int = 6
+    NoPathInField: int = 7
+    NoTime: int = 8
+    ForwardsReqTooFast: int = 9
+    BackwardsReqTooFast: int = 10
+    AerialNotReady: int = 11
+    AerialBadApproach: int = 12
+    AerialTurnTooSlow: int = 13
+    AerialTooMuchAcceleration: int = 14
+    AerialBoostShortfall: int = 15
+    AerialTooFast: int = 16
+    PathBlocked: int = 17
+
+
+class SliceDiagnostic:
+    slice_num: int
+    time: float
+    shot_type: Optional[ShotType]
+    rejection: Optional[ShotRejection]
+
+    def __str__(self) -> str: ...
+
+
+def get_shot_diagnostics(target_id: int, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[SliceDiagnostic]:
+    """
+    Runs the same search as get_shot_with_target, but returns the reason each ball slice was rejected
+
+    A slice with no rejection is a viable shot. The search stops at the first viable slice unless the target's options have all=True
+    """
+
+
+class RankedShot:
+    shot: BasicShotInfo
+    slice_num: int
+    time_to_contact: float
+    speed_margin: float
+    boost_cost: float
+    alignment: Optional[float]
+    ball_speed_toward_goal: float
+    score: float
+
+    def __str__(self) -> str: ...
+
+
+def get_ranked_shots(target_id: int, count: Optional[int]=None, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[RankedShot]:
+    """
+    Finds every viable shot in the target's range of slices and ranks them from the highest score to the lowest
+
+    count: The maximum number of shots to return, default is all of them
+    speed_margin: How much faster the car could be going than the average speed that the shot requires
+    boost_cost: An estimate of how much boost the shot will use
+    alignment: How closely the ball will be hit towards the middle of the target, from -1 to 1, if the target has posts
+    ball_speed_toward_goal: An estimate of how fast the ball will be going towards the opponent's goal right after the hit
+
+    When the target's options have rank=True, get_shot_with_target returns the highest ranked shot instead of the earliest one
+    """
+
+
+class CarIntercept:
+    car_index: int
+    team: int
+    slice_num: Optional[int]
+    time: Optional[float]
+    shot_type: Optional[ShotType]
+
+    def __str__(self) -> str: ...
+
+
+def get_all_car_intercepts(options: Optional[TargetOptions]=None, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[CarIntercept]:
+    """
+    Finds the earliest ball slice that every car can hit, in the same order as the cars in the game tick packet
+
+    Runs the same search as get_shot_with_target does for a target from new_any_target, without creating any targets
+    The options apply to every car, except that all and rank are ignored
+    slice_num, time and shot_type are None if the car can't hit any of the ball slices
+    """
+
+
+class BoostPadOptions:
+    own_half: Optional[bool]
+    between: Optional[tuple[float, float, float]]
+
+    def __init__(self, own_half: Optional[bool]=None, between: Optional[tuple[float, float, float]]=None) -> BoostPadOptions:
+        """
+        own_half: Only consider pads on the car's own half of the field
+        between: Only consider pads that are a small detour from driving straight to this point, and arrive at the pad facing it
+        """
+
+
+class BoostPadRoute:
+    pad_index: int
+    location: tuple[float, float, float]
+    is_full_boost: bool
+    time: float
+    distance: float
+    path_samples: list[tuple[float, float]]
+
+    def __str__(self) -> str: ...
+
+
+def get_boost_pad_routes(car_index: int, options: Optional[BoostPadOptions]=None) -> list[BoostPadRoute]:
+    """
+    Gets the routes to every boost pad that the car can reach before the end of the ball prediction, soonest first
+
+    time is the number of seconds from now until the car can drive over the pad, including waiting for it to respawn
+    Raises a LookupError on hoops and throwback if set_field_info hasn't been called
     """
 
 
@@ -165,9 +362,14 @@ class AdvancedShotInfo:
     final_target: tuple[float, float, float]
     distance_remaining: float
     required_jump_time: Optional[float]
-    path_samples: list[tuple[float, float]]
+    path_samples: list[tuple[float, float, float]]
     current_path_point: tuple[float, float, float]
     num_jumps: Optional[int]
+    leave_ceiling_location: Optional[tuple[float, float, float]]
+    leave_ceiling_time: Optional[float]
+    dodge_time: Optional[float]
+    dodge_direction: Optional[tuple[float, float]]
+    post_dodge_ball_velocity: Optional[tuple[float, float, float]]
 
     def __str__(self) -> str: ...
 
@@ -175,4 +377,111 @@ class AdvancedShotInfo:
 def get_data_for_shot_with_target(target_id: int) -> AdvancedShotInfo:
     """
     Gets information about the found shot
+
+    For wall shots, final_target, path_samples and current_path_point follow the path up the wall
+
+    For ceiling shots, these follow the path to the ceiling until the car leaves it at leave_ceiling_location,
+    then describe the aerial to the ball
+
+    For dodge shots, dodge dodge_time seconds after jumping, in dodge_direction relative to the car (+x is forwards, +y is right)
+    """
+
+
+class ShotOutcome(Enum):
+    Goal: int = 0
+    OwnGoal: int = 1
+    Post: int = 2
+    Backboard: int = 3
+    Miss: int = 4
+
+
+class PostHitPrediction:
+    time: float
+    location: tuple[float, float, float]
+    velocity: tuple[float, float, float]
+    outcome: Optional[ShotOutcome]
+    outcome_time: Optional[float]
+
+    def __str__(self) -> str: ...
+
+
+def get_post_hit_prediction(target_id: int, prediction_time: float=6.) -> PostHitPrediction:
+    """
+    Estimates how the car will hit the ball with the found shot, then predicts where the ball goes next
+
+    outcome is what the ball hits first: the opponent's goal, the car's own goal, the opponent's goal frame, or the back wall around it
+    It's None in arenas without goals, and post and backboard hits are only detected in standard soccer arenas
+
+    prediction_time: The number of seconds after the hit to predict the ball for
+    """
+
+
+class ControllerState:
     """
+    The inputs for a single tick, in the same layout as RLBot's SimpleControllerState
+    """
+
+    throttle: float
+    steer: float
+    pitch: float
+    yaw: float
+    roll: float
+    jump: bool
+    boost: bool
+    handbrake: bool
+
+    def __str__(self) -> str: ...
+
+
+def get_controls_for_shot(target_id: int) -> ControllerState:
+    """
+    Gets the controls for this tick that follow the found shot
+
+    For ground based shots, the car drives along the path at the speed that gets it to the ball on time, then jumps or dodges into the ball
+    For aerials, the car jumps if it needs to, then turns towards and boosts in the direction that it needs to accelerate in
+    For ceiling shots, the car drives onto the ceiling, lets go of the throttle to drop off of it, then does the aerial
+
+    Call this every tick, after tick()
+    """
+
+
+class World:
+    """
+    An independent analysis session that owns its own game, ball prediction, cars, mutators and targets
+
+    The module-level functions are all wrappers around a single default World
+    """
+
+    def __init__(self) -> World: ...
+    def __copy__(self) -> World:
+        """
+        Creates an independent copy of the session, useful for "what-if" analysis
+        """
+    def load_standard(self) -> None: ...
+    def load_dropshot(self) -> None: ...
+    def load_hoops(self) -> None: ...
+    def load_standard_throwback(self) -> None: ...
+    def set_mutator_settings(self, mutators: MutatorSettings) -> None: ...
+    def set_field_info(self, field_info: FieldInfoPacket) -> None: ...
+    def tick(self, packet: GameTickPacket, prediction_time: float=6.) -> None: ...
+    def get_slice_index(self, i: int) -> BallSlice: ...
+    def get_slice(self, time: float) -> BallSlice: ...
+    def get_num_ball_slices(self) -> int: ...
+    def get_game_state(self) -> GameState: ...
+    def get_own_goal(self, car_index: int) -> tuple[tuple[float, float, float], tuple[float, float, float]]: ...
+    def get_opponent_goal(self, car_index: int) -> tuple[tuple[float, float, float], tuple[float, float, float]]: ...
+    def new_target(self, left_target: tuple[float, float, float], right_target: tuple[float, float, float], car_index: int, options: Optional[TargetOptions]=None) -> int: ...
+    def new_any_target(self, car_index: int, options: Optional[TargetOptions]=None) -> int: ...
+    def new_location_target(self, car_index: int, location: tuple[float, float, float], arrival_yaw: Optional[float]=None, arrival_time: Optional[float]=None, options: Optional[TargetOptions]=None) -> int: ...
+    def confirm_target(self, target_id: int) -> None: ...
+    def remove_target(self, target_id: int) -> None: ...
+    def print_targets(self) -> None: ...
+    def get_targets_length(self) -> int: ...
+    def get_shot_with_target(self, target_id: int, temporary: bool=False, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> BasicShotInfo: ...
+    def get_ranked_shots(self, target_id: int, count: Optional[int]=None, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[RankedShot]: ...
+    def get_shot_diagnostics(self, target_id: int, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[SliceDiagnostic]: ...
+    def get_all_car_intercepts(self, options: Optional[TargetOptions]=None, may_ground_shot: Optional[bool]=None, may_jump_shot: Optional[bool]=None, may_double_jump_shot: Optional[bool]=None, may_aerial_shot: Optional[bool]=None, only: bool=False, may_ceiling_shot: Optional[bool]=None, may_dodge_shot: Optional[bool]=None) -> list[CarIntercept]: ...
+    def get_boost_pad_routes(self, car_index: int, options: Optional[BoostPadOptions]=None) -> list[BoostPadRoute]: ...
+    def get_data_for_shot_with_target(self, target_id: int) -> AdvancedShotInfo: ...
+    def get_controls_for_shot(self, target_id: int) -> ControllerState: ...
+    def get_post_hit_prediction(self, target_id: int, prediction_time: float=6.) -> PostHitPrediction: ...